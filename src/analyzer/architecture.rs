@@ -1,72 +1,125 @@
 use anyhow::Result;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
-use walkdir::WalkDir;
 
-use crate::analyzer::{Issue, IssueType, Severity};
-use crate::config::Config;
-use crate::parser::{self, GoFile};
+use crate::analyzer::{Issue, Rule};
+use crate::config::{ArchitectureRules, Config, ForbiddenImport};
+use crate::glob::Glob;
+use crate::parser::GoFile;
+use crate::project::{self, metrics, Project};
 
-pub fn analyze(ast: &GoFile, path: &Path, config: &Config, issues: &mut Vec<Issue>) -> Result<()> {
+struct Layer {
+    name: String,
+    globs: Vec<Glob>,
+}
+
+pub struct Forbidden<'a> {
+    entry: &'a ForbiddenImport,
+    path: Glob,
+    scopes: Vec<Glob>,
+}
+
+pub fn analyze(
+    ast: &GoFile,
+    path: &Path,
+    project: &Project,
+    config: &Config,
+    forbidden: &[Forbidden],
+    issues: &mut Vec<Issue>,
+) -> Result<()> {
     if config.rules.architecture.enforce_package_boundaries {
-        check_package_boundaries(ast, path, issues)?;
+        check_package_boundaries(ast, path, project, issues)?;
+    }
+    
+    if !forbidden.is_empty() {
+        check_forbidden_imports(ast, path, project, forbidden, issues)?;
     }
     
+    Ok(())
+}
+
+pub fn compile_forbidden_imports(rules: &ArchitectureRules) -> Result<Vec<Forbidden<'_>>> {
+    rules
+        .forbidden_imports
+        .iter()
+        .map(|entry| {
+            let scopes = entry
+                .scope
+                .iter()
+                .map(|pattern| Glob::new(pattern))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Forbidden {
+                entry,
+                path: Glob::new(&entry.path)?,
+                scopes,
+            })
+        })
+        .collect()
+}
+
+pub fn analyze_project(
+    project: &Project,
+    targets: &HashSet<PathBuf>,
+    config: &Config,
+    issues: &mut Vec<Issue>,
+) -> Result<()> {
     if config.rules.architecture.detect_circular_dependencies {
-        let project_dir = find_project_root(path);
-        check_circular_dependencies(&project_dir, path, config, issues)?;
+        check_circular_dependencies(project, targets, issues)?;
+    }
+    
+    if config.rules.architecture.enforce_package_boundaries && !config.rules.architecture.layers.is_empty() {
+        check_layers(project, targets, &config.rules.architecture, issues)?;
+    }
+    
+    if let Some(max_instability) = config.rules.architecture.max_instability {
+        let min_dependents = config.rules.architecture.min_dependents.unwrap_or(1);
+        check_unstable_packages(project, targets, max_instability, min_dependents, issues)?;
     }
     
     Ok(())
 }
 
-fn check_package_boundaries(ast: &GoFile, path: &Path, issues: &mut Vec<Issue>) -> Result<()> {
-    let package_node = ast.find_nodes("package_clause ").first().cloned();
-    let import_specs = ast.find_nodes("import_spec ");
+fn check_package_boundaries(
+    ast: &GoFile,
+    path: &Path,
+    project: &Project,
+    issues: &mut Vec<Issue>,
+) -> Result<()> {
+    if ast.find_nodes("package_clause").is_empty() {
+        return Ok(());
+    }
     
-    if let Some(package_node) = package_node {
-        if let Some(name_node) = package_node.child_by_field_name("name ") {
-            let _package_name = ast.get_snippet(name_node.start_byte(), name_node.end_byte());
-            let current_package_path = extract_package_path(path);
-            for import_spec in import_specs {
-                if let Some(path_node) = import_spec.child_by_field_name("path ") {
-                    let import_path = ast.get_snippet(path_node.start_byte(), path_node.end_byte());
-                    let import_path = import_path.trim_matches('"');
-                    let (line, column) = ast.get_position(import_spec.start_byte());
-                    if import_path.contains(&current_package_path) && !import_path.ends_with(&current_package_path) {
-                        let issue = Issue {
-                            file_path: path.to_path_buf(),
-                            line,
-                            column,
-                            issue_type: IssueType::Architecture,
-                            severity: Severity::Warning,
-                            message: format!(
-                                "Importing from the same module but different directory: {}. Consider restructuring.",
-                                import_path
-                            ),
-                            code: import_path.to_string(),
-                            fix_available: false,
-                        };
-                        
-                        issues.push(issue);
-                    }
-                    if import_path.contains("/internal/") && !path.to_string_lossy().contains("/internal/") {
-                        let issue = Issue {
-                            file_path: path.to_path_buf(),
-                            line,
-                            column,
-                            issue_type: IssueType::Architecture,
-                            severity: Severity::Error,
-                            message: format!(
-                                "Importing from an 'internal' package that should not be imported directly: {}",
-                                import_path
-                            ),
-                            code: import_path.to_string(),
-                            fix_available: false,
-                        };
-                        
-                        issues.push(issue);
-                    }
+    let current_package_path = project
+        .package_of(path)
+        .map_or_else(|| project::package_path_for(path), |package| package.path.clone());
+    for import_spec in ast.find_nodes("import_spec") {
+        if let Some(path_node) = import_spec.child_by_field_name("path") {
+            let import_path = ast.get_snippet(path_node.start_byte(), path_node.end_byte());
+            let import_path = import_path.trim_matches('"');
+            let (line, column) = ast.get_position(import_spec.start_byte());
+            if let Some(allowed_root) = internal_root(import_path) {
+                if !is_within(project, &current_package_path, allowed_root) {
+                    let allowed = if allowed_root.is_empty() {
+                        "the standard library".to_string()
+                    } else {
+                        format!("packages under {}", allowed_root)
+                    };
+                    let issue = Issue {
+                        file_path: path.to_path_buf(),
+                        line,
+                        column,
+                        rule: Rule::InternalImport,
+                        issue_type: Rule::InternalImport.issue_type(),
+                        severity: Rule::InternalImport.default_severity(),
+                        message: format!(
+                            "Use of internal package {} not allowed from {}: it may only be imported by {}",
+                            import_path, current_package_path, allowed
+                        ),
+                        code: import_path.to_string(),
+                        fix_available: false,
+                    };
+                    
+                    issues.push(issue);
                 }
             }
         }
@@ -75,59 +128,53 @@ fn check_package_boundaries(ast: &GoFile, path: &Path, issues: &mut Vec<Issue>)
     Ok(())
 }
 
-fn check_circular_dependencies(
-    project_dir: &Path,
-    current_file: &Path,
-    _config: &Config,
+fn check_forbidden_imports(
+    ast: &GoFile,
+    path: &Path,
+    project: &Project,
+    forbidden: &[Forbidden],
     issues: &mut Vec<Issue>,
 ) -> Result<()> {
-    let mut dependency_graph = HashMap::new();
-    let mut package_files = HashMap::new();
-    for entry in WalkDir::new(project_dir).follow_links(true) {
-        let entry = entry?;
-        let path = entry.path();
-        
-        if path.is_file() && path.extension().map_or(false, |ext| ext == "go ") {
-            if let Ok(file_ast) = parser::parse_file(path) {
-                if let Some(package_node) = file_ast.find_nodes("package_clause ").first() {
-                    if let Some(name_node) = package_node.child_by_field_name("name ") {
-                        let _package_name = file_ast.get_snippet(name_node.start_byte(), name_node.end_byte());
-                        let package_path = extract_package_path(path);
-                        package_files.entry(package_path.clone())
-                            .or_insert_with(Vec::new)
-                            .push(path.to_path_buf());
-                        let import_specs = file_ast.find_nodes("import_spec ");
-                        let mut imports = Vec::new();
-                        
-                        for import_spec in import_specs {
-                            if let Some(path_node) = import_spec.child_by_field_name("path ") {
-                                let import_path = file_ast.get_snippet(path_node.start_byte(), path_node.end_byte());
-                                let import_path = import_path.trim_matches('"');
-                                imports.push(import_path.to_string());
-                            }
-                        }
-                        dependency_graph.entry(package_path.clone())
-                            .or_insert_with(HashSet::new)
-                            .extend(imports);
-                    }
-                }
-            }
-        }
+    let package = project.package_of(path);
+    let package_path = package.map_or_else(|| project::package_path_for(path), |package| package.path.clone());
+    let relative_path = package.map_or(package_path.as_str(), |package| package.relative_path());
+    
+    let banned: Vec<&Forbidden> = forbidden
+        .iter()
+        .filter(|rule| {
+            rule.scopes.is_empty()
+                || rule
+                    .scopes
+                    .iter()
+                    .any(|scope| scope.is_match(relative_path) || scope.is_match(&package_path))
+        })
+        .collect();
+    if banned.is_empty() {
+        return Ok(());
     }
-    let current_package = extract_package_path(current_file);
-    let visited = HashSet::new();
-    let path = Vec::new();
     
-    if let Some(cycle) = find_cycle(&dependency_graph, &current_package, &visited, &path) {
-        if let Some(line) = find_import_line(current_file, &cycle.last().unwrap_or(&String::new())) {
+    for import_spec in ast.find_nodes("import_spec") {
+        if let Some(path_node) = import_spec.child_by_field_name("path") {
+            let import_path = ast.get_snippet(path_node.start_byte(), path_node.end_byte());
+            let import_path = import_path.trim_matches(|c| c == '"' || c == '`');
+            let Some(entry) = banned.iter().find(|rule| rule.path.is_match(import_path)).map(|rule| rule.entry) else {
+                continue;
+            };
+            
+            let (line, column) = ast.get_position(import_spec.start_byte());
+            let mut message = format!("Import of {} is forbidden: {}", import_path, entry.reason);
+            if let Some(replacement) = &entry.replacement {
+                message.push_str(&format!(". Use {} instead", replacement));
+            }
             let issue = Issue {
-                file_path: current_file.to_path_buf(),
+                file_path: path.to_path_buf(),
                 line,
-                column: 1,
-                issue_type: IssueType::Architecture,
-                severity: Severity::Error,
-                message: format!("Circular dependency detected: {}", cycle.join(" -> ")),
-                code: format!("Circular dependency path: {}", cycle.join(" -> ")),
+                column,
+                rule: Rule::ForbiddenImport,
+                issue_type: Rule::ForbiddenImport.issue_type(),
+                severity: Rule::ForbiddenImport.default_severity(),
+                message,
+                code: import_path.to_string(),
                 fix_available: false,
             };
             
@@ -138,86 +185,371 @@ fn check_circular_dependencies(
     Ok(())
 }
 
-fn find_cycle(
-    graph: &HashMap<String, HashSet<String>>,
-    current: &str,
-    visited: &HashSet<String>,
-    path: &Vec<String>,
-) -> Option<Vec<String>> {
-    let mut new_visited = visited.clone();
-    let mut new_path = path.clone();
-    if visited.contains(current) {
-        if let Some(start_idx) = path.iter().position(|p| p == current) {
-            let mut cycle = path[start_idx..].to_vec();
-            cycle.push(current.to_string());
-            return Some(cycle);
-        }
-        return None;
+fn internal_root(import_path: &str) -> Option<&str> {
+    if import_path == "internal" || (import_path.starts_with("internal/") && !import_path.contains("/internal")) {
+        return Some("");
     }
-    
-    new_visited.insert(current.to_string());
-    new_path.push(current.to_string());
-    if let Some(deps) = graph.get(current) {
-        for dep in deps {
-            if let Some(cycle) = find_cycle(graph, dep, &new_visited, &new_path) {
-                return Some(cycle);
-            }
+    if let Some(root) = import_path.strip_suffix("/internal") {
+        return Some(root);
+    }
+    import_path.rfind("/internal/").map(|index| &import_path[..index])
+}
+
+fn is_within(project: &Project, package_path: &str, root: &str) -> bool {
+    if root.is_empty() {
+        if project.modules.iter().any(|module| is_under(package_path, &module.module)) {
+            return false;
         }
+        let first_element = package_path.split('/').next().unwrap_or("");
+        return !first_element.contains('.');
     }
-    
-    None
+    is_under(package_path, root)
 }
 
-fn find_import_line(file_path: &Path, import_pkg: &str) -> Option<usize> {
-    if let Ok(ast) = parser::parse_file(file_path) {
-        let import_specs = ast.find_nodes("import_spec ");
+fn is_under(package_path: &str, root: &str) -> bool {
+    package_path == root || package_path.strip_prefix(root).is_some_and(|rest| rest.starts_with('/'))
+}
+
+fn check_layers(
+    project: &Project,
+    targets: &HashSet<PathBuf>,
+    rules: &ArchitectureRules,
+    issues: &mut Vec<Issue>,
+) -> Result<()> {
+    let layers = compile_layers(rules)?;
+    for package in project.packages.values() {
+        let from_layers = layers_of(&layers, package.relative_path(), &package.path);
+        if from_layers.is_empty() {
+            continue;
+        }
         
-        for spec in import_specs {
-            if let Some(path_node) = spec.child_by_field_name("path ") {
-                let import_path = ast.get_snippet(path_node.start_byte(), path_node.end_byte());
-                if import_path.contains(import_pkg) {
-                    return Some(ast.get_position(spec.start_byte()).0);
+        let files = package
+            .files
+            .iter()
+            .filter(|file| !file.is_test() && targets.contains(&file.path));
+        for file in files {
+            for import in &file.imports {
+                let to_layers = match project.packages.get(&import.path) {
+                    Some(target) => layers_of(&layers, target.relative_path(), &target.path),
+                    None => layers_of(&layers, &import.path, &import.path),
+                };
+                for from in &from_layers {
+                    let Some(rule) = rules.layer_rules.get(*from) else {
+                        continue;
+                    };
+                    for to in to_layers.iter().filter(|to| *to != from) {
+                        let denied = rule.deny.iter().any(|layer| layer == *to);
+                        let not_allowed = rule
+                            .allow
+                            .as_ref()
+                            .is_some_and(|allow| !allow.iter().any(|layer| layer == *to));
+                        if !denied && !not_allowed {
+                            continue;
+                        }
+                        
+                        let issue = Issue {
+                            file_path: file.path.clone(),
+                            line: import.line,
+                            column: import.column,
+                            rule: Rule::PackageBoundary,
+                            issue_type: Rule::PackageBoundary.issue_type(),
+                            severity: Rule::PackageBoundary.default_severity(),
+                            message: format!(
+                                "Layer '{}' must not depend on layer '{}': {} imports {}",
+                                from, to, package.path, import.path
+                            ),
+                            code: import.path.clone(),
+                            fix_available: false,
+                        };
+                        
+                        issues.push(issue);
+                    }
                 }
             }
         }
     }
     
-    None
+    Ok(())
 }
 
-fn extract_package_path(file_path: &Path) -> String {
-    let path_str = file_path.to_string_lossy();
-    if let Some(src_idx) = path_str.find("/src/") {
-        let after_src = &path_str[src_idx + 5..];
-        let dir_path = Path::new(after_src).parent().unwrap_or(Path::new(""));
-        return dir_path.to_string_lossy().to_string();
+fn compile_layers(rules: &ArchitectureRules) -> Result<Vec<Layer>> {
+    let mut layers = Vec::new();
+    for (name, patterns) in &rules.layers {
+        let globs = patterns
+            .patterns()
+            .iter()
+            .map(|pattern| Glob::new(pattern))
+            .collect::<Result<Vec<_>>>()?;
+        layers.push(Layer {
+            name: name.clone(),
+            globs,
+        });
+    }
+    
+    for (from, rule) in &rules.layer_rules {
+        let referenced = std::iter::once(from)
+            .chain(rule.allow.iter().flatten())
+            .chain(rule.deny.iter());
+        for name in referenced {
+            if !rules.layers.contains_key(name) {
+                return Err(anyhow::anyhow!("Unknown layer '{}' in architecture layer_rules", name));
+            }
+        }
     }
-    file_path
-        .parent()
-        .unwrap_or(Path::new(""))
-        .to_string_lossy()
-        .to_string()
+    
+    Ok(layers)
+}
+
+fn layers_of<'a>(layers: &'a [Layer], relative_path: &str, import_path: &str) -> Vec<&'a String> {
+    layers
+        .iter()
+        .filter(|layer| {
+            layer
+                .globs
+                .iter()
+                .any(|glob| glob.is_match(relative_path) || glob.is_match(import_path))
+        })
+        .map(|layer| &layer.name)
+        .collect()
 }
 
-fn find_project_root(file_path: &Path) -> PathBuf {
-    let mut current = file_path;
-    if current.is_file() {
-        current = current.parent().unwrap_or(Path::new(""));
+fn check_unstable_packages(
+    project: &Project,
+    targets: &HashSet<PathBuf>,
+    max_instability: f64,
+    min_dependents: usize,
+    issues: &mut Vec<Issue>,
+) -> Result<()> {
+    for package_metrics in metrics::compute(project) {
+        if package_metrics.instability <= max_instability || package_metrics.afferent < min_dependents {
+            continue;
+        }
+        
+        let Some(file) = project.packages.get(&package_metrics.package).and_then(|package| {
+            package
+                .files
+                .iter()
+                .find(|file| !file.is_test() && targets.contains(&file.path))
+        }) else {
+            continue;
+        };
+        
+        let issue = Issue {
+            file_path: file.path.clone(),
+            line: file.package_line,
+            column: file.package_column,
+            rule: Rule::UnstableDependency,
+            issue_type: Rule::UnstableDependency.issue_type(),
+            severity: Rule::UnstableDependency.default_severity(),
+            message: format!(
+                "Package {} is unstable (I={:.2} > {:.2}) but {} packages depend on it",
+                package_metrics.package, package_metrics.instability, max_instability, package_metrics.afferent
+            ),
+            code: format!(
+                "Ca={} Ce={} I={:.2}",
+                package_metrics.afferent, package_metrics.efferent, package_metrics.instability
+            ),
+            fix_available: false,
+        };
+        
+        issues.push(issue);
     }
     
-    loop {
-        let go_mod = current.join("go.mod ");
-        if go_mod.exists() {
-            return current.to_path_buf();
-        }
-        let src_dir = current.join("src ");
-        if src_dir.exists() && src_dir.is_dir() {
-            return current.to_path_buf();
+    Ok(())
+}
+
+fn check_circular_dependencies(
+    project: &Project,
+    targets: &HashSet<PathBuf>,
+    issues: &mut Vec<Issue>,
+) -> Result<()> {
+    for cycle in project.import_graph().cycles() {
+        let import_site = cycle.windows(2).find_map(|edge| {
+            let package = project.packages.get(&edge[0])?;
+            package
+                .files
+                .iter()
+                .filter(|file| !file.is_test() && targets.contains(&file.path))
+                .find_map(|file| {
+                    file.imports
+                        .iter()
+                        .find(|import| import.path == edge[1])
+                        .map(|import| (file, import))
+                })
+        });
+        
+        if let Some((file, import)) = import_site {
+            let issue = Issue {
+                file_path: file.path.clone(),
+                line: import.line,
+                column: import.column,
+                rule: Rule::CircularDependency,
+                issue_type: Rule::CircularDependency.issue_type(),
+                severity: Rule::CircularDependency.default_severity(),
+                message: format!("Circular dependency detected: {}", cycle.join(" -> ")),
+                code: format!("Circular dependency path: {}", cycle.join(" -> ")),
+                fix_available: false,
+            };
+            
+            issues.push(issue);
         }
-        match current.parent() {
-            Some(parent) => current = parent,
-            None => break,
+    }
+    
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::{self, IssueType};
+    use crate::project::GoMod;
+    use crate::test_support::TempDir;
+
+    fn project_with_module(module: &str) -> Project {
+        let mut project = Project::default();
+        project.modules.push(GoMod {
+            dir: PathBuf::from("/src"),
+            module: module.to_string(),
+            go_version: None,
+            requires: Vec::new(),
+            replaces: Vec::new(),
+            excludes: Vec::new(),
+        });
+        project
+    }
+
+    #[test]
+    fn internal_root_uses_last_internal_element() {
+        assert_eq!(internal_root("a/internal/b/internal"), Some("a/internal/b"));
+        assert_eq!(internal_root("a/internal/b/internal/c"), Some("a/internal/b"));
+        assert_eq!(internal_root("a/internal"), Some("a"));
+        assert_eq!(internal_root("internal/poll"), Some(""));
+        assert_eq!(internal_root("internal/a/internal/b"), Some("internal/a"));
+        assert_eq!(internal_root("a/internals/b"), None);
+    }
+
+    #[test]
+    fn stdlib_internal_is_not_visible_to_dotless_modules() {
+        let project = project_with_module("myapp");
+        assert!(!is_within(&project, "myapp/cmd", ""));
+        assert!(is_within(&Project::default(), "net/http", ""));
+        assert!(!is_within(&Project::default(), "example.com/app", ""));
+    }
+
+    #[test]
+    fn nested_packages_are_within_root() {
+        let project = Project::default();
+        assert!(is_within(&project, "a/internal/b", "a/internal/b"));
+        assert!(is_within(&project, "a/internal/b/c", "a/internal/b"));
+        assert!(!is_within(&project, "a/c", "a/internal/b"));
+        assert!(!is_within(&project, "ab", "a"));
+    }
+
+    #[test]
+    fn root_package_may_import_its_own_subpackages() {
+        let dir = TempDir::new("boundaries");
+        dir.write("go.mod", "module github.com/acme/svc\n");
+        dir.write(
+            "main.go",
+            "package main\n\nimport (\n\t\"github.com/acme/svc/db\"\n\t\"github.com/acme/svc/internal/auth\"\n)\n\nfunc main() {\n\tdb.Open()\n\tauth.Check()\n}\n",
+        );
+        dir.write("db/db.go", "package db\n\nfunc Open() {}\n");
+        dir.write("internal/auth/auth.go", "package auth\n\nfunc Check() {}\n");
+
+        let found: Vec<Rule> = analyzer::run_analysis(dir.path(), &Config::default(), None)
+            .unwrap()
+            .into_iter()
+            .map(|issue| issue.rule)
+            .filter(|rule| matches!(rule.issue_type(), IssueType::Architecture))
+            .collect();
+        assert!(found.is_empty(), "{:?}", found);
+    }
+
+    #[test]
+    fn reports_unstable_packages_with_dependents() {
+        let dir = TempDir::new("unstable");
+        dir.write("go.mod", "module example.com/m\n");
+        dir.write("a/a.go", "package a\n\nimport _ \"example.com/m/shared\"\n");
+        dir.write("b/b.go", "package b\n\nimport _ \"example.com/m/shared\"\n");
+        dir.write(
+            "shared/shared.go",
+            "package shared\n\nimport (\n\t_ \"example.com/m/x\"\n\t_ \"example.com/m/y\"\n\t_ \"example.com/m/z\"\n)\n",
+        );
+        for name in ["x", "y", "z"] {
+            dir.write(&format!("{}/{}.go", name, name), &format!("package {}\n", name));
         }
+
+        let mut config = Config::default();
+        config.rules.architecture.max_instability = Some(0.5);
+        let unstable = |config: &Config| -> Vec<(String, usize)> {
+            analyzer::run_analysis(dir.path(), config, None)
+                .unwrap()
+                .into_iter()
+                .filter(|issue| issue.rule == Rule::UnstableDependency)
+                .map(|issue| (issue.file_path.file_name().unwrap().to_string_lossy().to_string(), issue.line))
+                .collect()
+        };
+        assert_eq!(unstable(&config), [("shared.go".to_string(), 1)]);
+
+        config.rules.architecture.min_dependents = Some(3);
+        assert!(unstable(&config).is_empty());
     }
-    file_path.parent().unwrap_or(Path::new("")).to_path_buf()
-} 
\ No newline at end of file
+
+    #[test]
+    fn reports_forbidden_imports_within_their_scope() {
+        let dir = TempDir::new("forbidden");
+        dir.write("go.mod", "module example.com/m\n");
+        dir.write(
+            "internal/crypto/keys.go",
+            "package crypto\n\nimport (\n\t\"io/ioutil\"\n\t\"math/rand\"\n)\n",
+        );
+        dir.write(
+            "web/web.go",
+            "package web\n\nimport (\n\t\"github.com/pkg/errors/wrap\"\n\t\"math/rand\"\n)\n",
+        );
+
+        let mut config = Config::default();
+        config.rules.architecture.forbidden_imports = vec![
+            ForbiddenImport {
+                path: "io/ioutil".to_string(),
+                reason: "deprecated since Go 1.16".to_string(),
+                replacement: Some("os or io".to_string()),
+                scope: Vec::new(),
+            },
+            ForbiddenImport {
+                path: "math/rand".to_string(),
+                reason: "not a CSPRNG".to_string(),
+                replacement: None,
+                scope: vec!["internal/crypto/**".to_string()],
+            },
+            ForbiddenImport {
+                path: "github.com/pkg/errors/**".to_string(),
+                reason: "use the standard errors package".to_string(),
+                replacement: None,
+                scope: vec!["example.com/m/web".to_string()],
+            },
+        ];
+        let found: Vec<(String, usize, String)> = analyzer::run_analysis(dir.path(), &config, None)
+            .unwrap()
+            .into_iter()
+            .filter(|issue| issue.rule == Rule::ForbiddenImport)
+            .map(|issue| (issue.file_path.file_name().unwrap().to_string_lossy().to_string(), issue.line, issue.message))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (
+                    "keys.go".to_string(),
+                    4,
+                    "Import of io/ioutil is forbidden: deprecated since Go 1.16. Use os or io instead".to_string()
+                ),
+                ("keys.go".to_string(), 5, "Import of math/rand is forbidden: not a CSPRNG".to_string()),
+                (
+                    "web.go".to_string(),
+                    4,
+                    "Import of github.com/pkg/errors/wrap is forbidden: use the standard errors package".to_string()
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,475 @@
+use std::collections::{HashMap, HashSet};
+use tree_sitter::Node;
+
+use crate::parser::{named_children, GoFile};
+
+const STATEMENT_KINDS: &[&str] = &[
+    "assignment_statement",
+    "dec_statement",
+    "expression_statement",
+    "inc_statement",
+    "send_statement",
+    "short_var_declaration",
+    "block",
+    "break_statement",
+    "const_declaration",
+    "continue_statement",
+    "defer_statement",
+    "empty_statement",
+    "expression_switch_statement",
+    "fallthrough_statement",
+    "for_statement",
+    "go_statement",
+    "goto_statement",
+    "if_statement",
+    "labeled_statement",
+    "return_statement",
+    "select_statement",
+    "type_declaration",
+    "type_switch_statement",
+    "var_declaration",
+];
+
+const TERMINATING_CALLS: &[&str] = &[
+    "panic",
+    "os.Exit",
+    "log.Fatal",
+    "log.Fatalf",
+    "log.Fatalln",
+    "log.Panic",
+    "log.Panicf",
+    "log.Panicln",
+    "runtime.Goexit",
+];
+
+#[derive(Debug, Clone, Default)]
+pub struct Block<'a> {
+    pub nodes: Vec<Node<'a>>,
+    pub successors: Vec<usize>,
+}
+
+#[derive(Debug)]
+pub struct Cfg<'a> {
+    pub blocks: Vec<Block<'a>>,
+    statements: Vec<(Node<'a>, usize)>,
+}
+
+struct Target {
+    label: Option<String>,
+    break_block: usize,
+    continue_block: Option<usize>,
+}
+
+struct Builder<'a, 'f> {
+    ast: &'f GoFile,
+    cfg: Cfg<'a>,
+    current: usize,
+    targets: Vec<Target>,
+    labels: HashMap<String, usize>,
+}
+
+impl<'a> Cfg<'a> {
+    pub const ENTRY: usize = 0;
+    pub const EXIT: usize = 1;
+
+    pub fn build(ast: &GoFile, body: Node<'a>) -> Cfg<'a> {
+        let mut builder = Builder {
+            ast,
+            cfg: Cfg {
+                blocks: vec![Block::default(), Block::default()],
+                statements: Vec::new(),
+            },
+            current: Cfg::ENTRY,
+            targets: Vec::new(),
+            labels: HashMap::new(),
+        };
+        builder.statements(statements(body));
+        builder.jump(Cfg::EXIT);
+        builder.cfg
+    }
+
+    pub fn reachable(&self) -> Vec<bool> {
+        let mut reachable = vec![false; self.blocks.len()];
+        let mut stack = vec![Cfg::ENTRY];
+        while let Some(block) = stack.pop() {
+            if reachable[block] {
+                continue;
+            }
+            reachable[block] = true;
+            stack.extend(self.blocks[block].successors.iter().copied());
+        }
+        reachable
+    }
+
+    pub fn unreachable_statements(&self) -> Vec<Node<'a>> {
+        let reachable = self.reachable();
+        let dead: HashSet<usize> = self
+            .statements
+            .iter()
+            .filter(|(_, block)| !reachable[*block])
+            .map(|(node, _)| node.id())
+            .collect();
+        let statements: HashSet<usize> = self.statements.iter().map(|(node, _)| node.id()).collect();
+
+        let mut unreachable = Vec::new();
+        for (node, _) in self.statements.iter().filter(|(node, _)| dead.contains(&node.id())) {
+            if node.kind() == "empty_statement" {
+                continue;
+            }
+            let enclosing = ancestors(*node).find(|n| statements.contains(&n.id()));
+            if enclosing.is_some_and(|n| dead.contains(&n.id())) {
+                continue;
+            }
+            let previous = previous_statement(*node);
+            if previous.is_some_and(|n| dead.contains(&n.id())) {
+                continue;
+            }
+            unreachable.push(*node);
+        }
+        unreachable
+    }
+}
+
+impl<'a> Builder<'a, '_> {
+    fn new_block(&mut self) -> usize {
+        self.cfg.blocks.push(Block::default());
+        self.cfg.blocks.len() - 1
+    }
+
+    fn edge(&mut self, from: usize, to: usize) {
+        if !self.cfg.blocks[from].successors.contains(&to) {
+            self.cfg.blocks[from].successors.push(to);
+        }
+    }
+
+    fn add(&mut self, node: Node<'a>) {
+        let current = self.current;
+        self.cfg.blocks[current].nodes.push(node);
+    }
+
+    fn jump(&mut self, to: usize) {
+        self.edge(self.current, to);
+        self.current = self.new_block();
+    }
+
+    fn label_block(&mut self, name: String) -> usize {
+        if let Some(&block) = self.labels.get(&name) {
+            return block;
+        }
+        let block = self.new_block();
+        self.labels.insert(name, block);
+        block
+    }
+
+    fn statements(&mut self, nodes: Vec<Node<'a>>) {
+        for node in nodes {
+            self.statement(node, None);
+        }
+    }
+
+    fn statement(&mut self, node: Node<'a>, label: Option<String>) {
+        if node.kind() != "labeled_statement" {
+            self.cfg.statements.push((node, self.current));
+        }
+        match node.kind() {
+            "block" => self.statements(statements(node)),
+            "labeled_statement" => {
+                let name = node.child_by_field_name("label").map(|l| self.snippet(l)).unwrap_or_default();
+                let block = self.label_block(name.clone());
+                self.edge(self.current, block);
+                self.current = block;
+                self.cfg.statements.push((node, self.current));
+                if let Some(inner) = statements(node).into_iter().next() {
+                    self.statement(inner, Some(name));
+                }
+            }
+            "return_statement" => {
+                self.add(node);
+                self.jump(Cfg::EXIT);
+            }
+            "expression_statement" => {
+                self.add(node);
+                if self.is_terminating_call(node) {
+                    self.jump(Cfg::EXIT);
+                }
+            }
+            "goto_statement" => {
+                self.add(node);
+                let name = label_name(node).map(|l| self.snippet(l)).unwrap_or_default();
+                let block = self.label_block(name);
+                self.jump(block);
+            }
+            "break_statement" | "continue_statement" => {
+                self.add(node);
+                let name = label_name(node).map(|l| self.snippet(l));
+                let is_break = node.kind() == "break_statement";
+                let target = self
+                    .targets
+                    .iter()
+                    .rev()
+                    .filter(|t| is_break || t.continue_block.is_some())
+                    .find(|t| name.is_none() || t.label == name)
+                    .map(|t| if is_break { t.break_block } else { t.continue_block.unwrap_or(t.break_block) });
+                match target {
+                    Some(target) => self.jump(target),
+                    None => self.jump(Cfg::EXIT),
+                }
+            }
+            "if_statement" => self.if_statement(node),
+            "for_statement" => self.for_statement(node, label),
+            "expression_switch_statement" | "type_switch_statement" => self.switch_statement(node, label),
+            "select_statement" => self.select_statement(node, label),
+            _ => self.add(node),
+        }
+    }
+
+    fn if_statement(&mut self, node: Node<'a>) {
+        if let Some(initializer) = node.child_by_field_name("initializer") {
+            self.add(initializer);
+        }
+        if let Some(condition) = node.child_by_field_name("condition") {
+            self.add(condition);
+        }
+        let head = self.current;
+        let after = self.new_block();
+
+        self.current = self.new_block();
+        self.edge(head, self.current);
+        if let Some(consequence) = node.child_by_field_name("consequence") {
+            self.statement(consequence, None);
+        }
+        self.edge(self.current, after);
+
+        match node.child_by_field_name("alternative") {
+            Some(alternative) => {
+                self.current = self.new_block();
+                self.edge(head, self.current);
+                self.statement(alternative, None);
+                self.edge(self.current, after);
+            }
+            None => self.edge(head, after),
+        }
+        self.current = after;
+    }
+
+    fn for_statement(&mut self, node: Node<'a>, label: Option<String>) {
+        let header = named_children(node)
+            .into_iter()
+            .find(|child| Some(*child) != node.child_by_field_name("body"));
+        let (initializer, condition, update) = match header {
+            Some(clause) if clause.kind() == "for_clause" => (
+                clause.child_by_field_name("initializer"),
+                clause.child_by_field_name("condition"),
+                clause.child_by_field_name("update"),
+            ),
+            Some(expression) => (None, Some(expression), None),
+            None => (None, None, None),
+        };
+        if let Some(initializer) = initializer {
+            self.add(initializer);
+        }
+
+        let head = self.new_block();
+        self.edge(self.current, head);
+        let after = self.new_block();
+        if let Some(condition) = condition {
+            self.current = head;
+            self.add(condition);
+            self.edge(head, after);
+        }
+        let continue_block = match update {
+            Some(update) => {
+                let block = self.new_block();
+                self.current = block;
+                self.add(update);
+                self.edge(block, head);
+                block
+            }
+            None => head,
+        };
+
+        self.current = self.new_block();
+        self.edge(head, self.current);
+        self.targets.push(Target {
+            label,
+            break_block: after,
+            continue_block: Some(continue_block),
+        });
+        if let Some(body) = node.child_by_field_name("body") {
+            self.statement(body, None);
+        }
+        self.targets.pop();
+        self.edge(self.current, continue_block);
+        self.current = after;
+    }
+
+    fn switch_statement(&mut self, node: Node<'a>, label: Option<String>) {
+        if let Some(initializer) = node.child_by_field_name("initializer") {
+            self.add(initializer);
+        }
+        if let Some(value) = node.child_by_field_name("value") {
+            self.add(value);
+        }
+        let cases: Vec<Node> = named_children(node)
+            .into_iter()
+            .filter(|child| matches!(child.kind(), "expression_case" | "type_case" | "default_case"))
+            .collect();
+        let head = self.current;
+        for case in &cases {
+            if let Some(value) = case.child_by_field_name("value") {
+                self.cfg.blocks[head].nodes.push(value);
+            }
+        }
+        let after = self.new_block();
+        let blocks: Vec<usize> = cases.iter().map(|_| self.new_block()).collect();
+        if !cases.iter().any(|case| case.kind() == "default_case") {
+            self.edge(head, after);
+        }
+
+        self.targets.push(Target {
+            label,
+            break_block: after,
+            continue_block: None,
+        });
+        for (index, case) in cases.iter().enumerate() {
+            self.edge(head, blocks[index]);
+            self.current = blocks[index];
+            let body = statements(*case);
+            let falls_through = body.last().is_some_and(|s| s.kind() == "fallthrough_statement");
+            self.statements(body);
+            match blocks.get(index + 1) {
+                Some(&next) if falls_through => self.edge(self.current, next),
+                _ => self.edge(self.current, after),
+            }
+        }
+        self.targets.pop();
+        self.current = after;
+    }
+
+    fn select_statement(&mut self, node: Node<'a>, label: Option<String>) {
+        let head = self.current;
+        let after = self.new_block();
+        self.targets.push(Target {
+            label,
+            break_block: after,
+            continue_block: None,
+        });
+        for case in named_children(node) {
+            self.current = self.new_block();
+            self.edge(head, self.current);
+            if let Some(communication) = case.child_by_field_name("communication") {
+                self.add(communication);
+            }
+            self.statements(statements(case));
+            self.edge(self.current, after);
+        }
+        self.targets.pop();
+        self.current = after;
+    }
+
+    fn is_terminating_call(&self, node: Node) -> bool {
+        let Some(call) = named_children(node).into_iter().next() else {
+            return false;
+        };
+        if call.kind() != "call_expression" {
+            return false;
+        }
+        call.child_by_field_name("function")
+            .is_some_and(|function| TERMINATING_CALLS.contains(&self.snippet(function).as_str()))
+    }
+
+    fn snippet(&self, node: Node) -> String {
+        self.ast.get_snippet(node.start_byte(), node.end_byte())
+    }
+}
+
+fn statements(node: Node) -> Vec<Node> {
+    let communication = node.child_by_field_name("communication");
+    named_children(node)
+        .into_iter()
+        .filter(|child| STATEMENT_KINDS.contains(&child.kind()) && Some(*child) != communication)
+        .collect()
+}
+
+fn label_name(node: Node) -> Option<Node> {
+    named_children(node).into_iter().find(|child| child.kind() == "label_name")
+}
+
+fn previous_statement(node: Node) -> Option<Node> {
+    let mut sibling = node.prev_named_sibling();
+    while let Some(candidate) = sibling {
+        if STATEMENT_KINDS.contains(&candidate.kind()) {
+            return Some(candidate);
+        }
+        sibling = candidate.prev_named_sibling();
+    }
+    None
+}
+
+fn ancestors(node: Node) -> impl Iterator<Item = Node> {
+    std::iter::successors(node.parent(), |n| n.parent())
+        .take_while(|n| !matches!(n.kind(), "function_declaration" | "method_declaration" | "func_literal"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::parse;
+
+    fn unreachable(body: &str) -> Vec<String> {
+        let ast = parse(&format!("package p\n\nfunc f(x int, ch chan int) {{\n{}\n}}\n", body));
+        let function = ast.find_nodes("function_declaration")[0];
+        let cfg = Cfg::build(&ast, function.child_by_field_name("body").unwrap());
+        cfg.unreachable_statements()
+            .into_iter()
+            .map(|node| ast.get_snippet(node.start_byte(), node.end_byte()))
+            .collect()
+    }
+
+    #[test]
+    fn reports_first_statement_after_return() {
+        assert_eq!(unreachable("\treturn\n\tprintln(1)\n\tprintln(2)"), ["println(1)"]);
+        assert_eq!(unreachable("\tpanic(\"no\")\n\tprintln(1)"), ["println(1)"]);
+        assert!(unreachable("\tif x > 0 {\n\t\treturn\n\t}\n\tprintln(1)").is_empty());
+    }
+
+    #[test]
+    fn follows_labeled_break_and_continue() {
+        let broken = "outer:\n\tfor {\n\t\tfor {\n\t\t\tbreak outer\n\t\t}\n\t}\n\tprintln(\"done\")";
+        assert!(unreachable(broken).is_empty());
+
+        let continued = "outer:\n\tfor i := 0; i < x; i++ {\n\t\tfor {\n\t\t\tcontinue outer\n\t\t\tprintln(\"skipped\")\n\t\t}\n\t}\n\tprintln(\"done\")";
+        assert_eq!(unreachable(continued), ["println(\"skipped\")"]);
+
+        assert_eq!(unreachable("\tfor {\n\t}\n\tprintln(\"never\")"), ["println(\"never\")"]);
+    }
+
+    #[test]
+    fn follows_goto() {
+        let forward = "\tgoto end\n\tprintln(\"skipped\")\nend:\n\tprintln(\"done\")";
+        assert_eq!(unreachable(forward), ["println(\"skipped\")"]);
+
+        let backward = "again:\n\tprintln(x)\n\tgoto again\n\tprintln(\"never\")";
+        assert_eq!(unreachable(backward), ["println(\"never\")"]);
+    }
+
+    #[test]
+    fn switch_fallthrough_joins_the_next_case() {
+        let exhaustive = "\tswitch x {\n\tcase 1:\n\t\tfallthrough\n\tcase 2:\n\t\treturn\n\tdefault:\n\t\treturn\n\t}\n\tprintln(\"after\")";
+        assert_eq!(unreachable(exhaustive), ["println(\"after\")"]);
+
+        let open = "\tswitch x {\n\tcase 1:\n\t\tprintln(1)\n\tcase 2:\n\t\treturn\n\tdefault:\n\t\treturn\n\t}\n\tprintln(\"after\")";
+        assert!(unreachable(open).is_empty());
+
+        let no_default = "\tswitch x {\n\tcase 1:\n\t\treturn\n\t}\n\tprintln(\"after\")";
+        assert!(unreachable(no_default).is_empty());
+    }
+
+    #[test]
+    fn select_blocks_without_cases() {
+        assert_eq!(unreachable("\tselect {}\n\tprintln(\"never\")"), ["println(\"never\")"]);
+
+        let received = "\tselect {\n\tcase v := <-ch:\n\t\tprintln(v)\n\t\tbreak\n\t\tprintln(\"skipped\")\n\t}\n\tprintln(\"after\")";
+        assert_eq!(unreachable(received), ["println(\"skipped\")"]);
+    }
+}
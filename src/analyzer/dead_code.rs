@@ -1,43 +1,212 @@
 use anyhow::Result;
 use std::collections::{HashMap, HashSet};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use crate::analyzer::{Issue, IssueType, Severity};
+use crate::analyzer::cfg::Cfg;
+use crate::analyzer::liveness;
+use crate::analyzer::scope::{BindingKind, ScopeTree};
+use crate::analyzer::{Issue, Rule};
 use crate::config::Config;
-use crate::parser::GoFile;
+use crate::fixes;
+use crate::glob::Glob;
+use crate::parser::{named_children, GoFile};
+use crate::project::{self, Declaration, DeclarationKind, Package, Project, SourceFile};
 
-pub fn analyze(ast: &GoFile, path: &Path, config: &Config, issues: &mut Vec<Issue>) -> Result<()> {
+const IMPLICIT_INTERFACE_METHODS: &[&str] = &[
+    "String", "GoString", "Format", "Error", "Unwrap", "Is", "As", "Timeout", "Temporary",
+    "MarshalJSON", "UnmarshalJSON", "MarshalText", "UnmarshalText", "MarshalBinary", "UnmarshalBinary",
+    "MarshalYAML", "UnmarshalYAML", "Scan", "Value", "ServeHTTP", "Read", "Write", "Close", "Seek",
+    "ReadFrom", "WriteTo", "Len", "Less", "Swap", "Push", "Pop", "Lock", "Unlock",
+];
+
+const REFLECTIVE_IMPORTS: &[&str] = &["reflect", "unsafe", "encoding/binary", "C"];
+
+struct ApiRoot {
+    package: Option<Glob>,
+    name: Glob,
+    full: Glob,
+}
+
+impl ApiRoot {
+    fn parse(root: &str) -> Result<ApiRoot> {
+        let name_start = root.rfind('/').map_or(0, |i| i + 1);
+        match root[name_start..].find('.') {
+            Some(dot) => Ok(ApiRoot {
+                package: Some(Glob::new(&root[..name_start + dot])?),
+                name: Glob::new(&root[name_start + dot + 1..])?,
+                full: Glob::new(root)?,
+            }),
+            None => Ok(ApiRoot {
+                package: None,
+                name: Glob::new(root)?,
+                full: Glob::new(root)?,
+            }),
+        }
+    }
+    
+    fn matches(&self, package: &Package, declaration: &Declaration) -> bool {
+        let package_matches = self
+            .package
+            .as_ref()
+            .is_none_or(|glob| glob.is_match(&package.path) || glob.is_match(package.relative_path()));
+        let name_matches = self.name.is_match(&declaration.qualified_name()) || self.name.is_match(&declaration.name);
+        (package_matches && name_matches) || self.full.is_match(&declaration.qualified_name())
+    }
+}
+
+pub fn analyze(
+    ast: &GoFile,
+    scopes: &ScopeTree,
+    path: &Path,
+    project: &Project,
+    config: &Config,
+    issues: &mut Vec<Issue>,
+) -> Result<()> {
     if config.rules.dead_code.detect_unused_imports {
         check_unused_imports(ast, path, issues)?;
     }
     
     if config.rules.dead_code.detect_unused_functions {
-        check_unused_functions(ast, path, issues)?;
+        check_unused_functions(ast, path, project, issues)?;
     }
     
     if config.rules.dead_code.detect_unused_variables {
-        check_unused_variables(ast, path, issues)?;
+        check_unused_variables(ast, scopes, path, project, issues)?;
+    }
+    
+    if config.rules.dead_code.detect_unused_parameters {
+        check_unused_parameters(ast, scopes, path, project, issues)?;
+    }
+    
+    if config.rules.dead_code.detect_unused_constants {
+        check_unused_constants(ast, path, project, issues)?;
+    }
+    
+    if config.rules.dead_code.detect_unused_types {
+        check_unused_types(ast, path, project, issues)?;
+    }
+    
+    if config.rules.dead_code.detect_unused_fields {
+        check_unused_fields(ast, path, project, issues)?;
+    }
+    
+    if config.rules.dead_code.detect_unused_methods {
+        check_unused_methods(ast, path, project, issues)?;
+    }
+    
+    if config.rules.dead_code.detect_unreachable_code {
+        check_unreachable_code(ast, path, issues)?;
+    }
+    
+    if config.rules.dead_code.detect_ineffectual_assignments {
+        check_ineffectual_assignments(ast, scopes, path, issues)?;
     }
     
     Ok(())
 }
 
+pub fn analyze_project(
+    project: &Project,
+    targets: &HashSet<PathBuf>,
+    config: &Config,
+    issues: &mut Vec<Issue>,
+) -> Result<()> {
+    if config.rules.dead_code.detect_unused_exported {
+        check_unused_exported(project, targets, &config.rules.dead_code.api_roots, issues)?;
+    }
+    
+    Ok(())
+}
+
+fn check_unused_exported(
+    project: &Project,
+    targets: &HashSet<PathBuf>,
+    api_roots: &[String],
+    issues: &mut Vec<Issue>,
+) -> Result<()> {
+    let api_roots = api_roots
+        .iter()
+        .map(|root| ApiRoot::parse(root))
+        .collect::<Result<Vec<_>>>()?;
+    let mut qualified_references: HashSet<(&str, &str)> = HashSet::new();
+    let mut member_references: HashSet<&str> = project.interface_methods().iter().map(String::as_str).collect();
+    for file in project.packages.values().flat_map(|package| &package.files) {
+        collect_qualified_references(project, file, &mut qualified_references);
+        member_references.extend(file.member_references.iter().map(String::as_str));
+    }
+    
+    for package in project.packages.values() {
+        for file in package.files.iter().filter(|f| !f.is_test() && targets.contains(&f.path)) {
+            let local_references = project.package_references(&file.path).unwrap_or_default();
+            for declaration in file.declarations.iter().filter(|d| d.is_exported()) {
+                let used = match declaration.kind {
+                    DeclarationKind::Method => {
+                        member_references.contains(declaration.name.as_str())
+                            || IMPLICIT_INTERFACE_METHODS.contains(&declaration.name.as_str())
+                    }
+                    _ => {
+                        local_references.contains(declaration.name.as_str())
+                            || qualified_references.contains(&(package.path.as_str(), declaration.name.as_str()))
+                    }
+                };
+                if used || api_roots.iter().any(|root| root.matches(package, declaration)) {
+                    continue;
+                }
+                
+                let issue = Issue {
+                    file_path: file.path.clone(),
+                    line: declaration.line,
+                    column: declaration.column,
+                    rule: Rule::UnusedExported,
+                    issue_type: Rule::UnusedExported.issue_type(),
+                    severity: Rule::UnusedExported.default_severity(),
+                    message: format!(
+                        "Exported {} {} is never used in the module",
+                        declaration.kind.describe(),
+                        declaration.qualified_name()
+                    ),
+                    code: declaration.qualified_name(),
+                    fix_available: false,
+                };
+                
+                issues.push(issue);
+            }
+        }
+    }
+    
+    Ok(())
+}
+
+fn collect_qualified_references<'a>(
+    project: &'a Project,
+    file: &'a SourceFile,
+    references: &mut HashSet<(&'a str, &'a str)>,
+) {
+    for import in file.imports.iter().filter(|import| import.alias.as_deref() == Some(".")) {
+        references.extend(file.references.iter().map(|name| (import.path.as_str(), name.as_str())));
+    }
+    let qualifiers = project.import_qualifiers(file);
+    
+    for (qualifier, name) in &file.qualified_references {
+        if let Some(import_path) = qualifiers.get(qualifier.as_str()) {
+            references.insert((import_path, name));
+        }
+    }
+}
+
 fn check_unused_imports(ast: &GoFile, path: &Path, issues: &mut Vec<Issue>) -> Result<()> {
-    let import_nodes = ast.find_nodes("import_spec ");
+    let import_nodes = ast.find_nodes("import_spec");
     let mut imports = HashMap::new();
     for node in &import_nodes {
-        if let Some(path_node) = node.child_by_field_name("path ") {
+        if let Some(path_node) = node.child_by_field_name("path") {
             let import_path = ast.get_snippet(path_node.start_byte(), path_node.end_byte());
             let (line, column) = ast.get_position(node.start_byte());
-            let import_alias = if let Some(name_node) = node.child_by_field_name("name ") {
-                Some(ast.get_snippet(name_node.start_byte(), name_node.end_byte()))
-            } else {
-                None
-            };
-            if import_alias.as_ref().map_or(false, |a| a == "_") {
+            let import_alias = node.child_by_field_name("name")
+                .map(|name_node| ast.get_snippet(name_node.start_byte(), name_node.end_byte()));
+            if import_alias.as_ref().is_some_and(|a| a == "_") {
                 continue;
             }
-            if import_alias.as_ref().map_or(false, |a| a == ".") {
+            if import_alias.as_ref().is_some_and(|a| a == ".") {
                 continue;
             }
             let package_name = extract_package_name(&import_path);
@@ -49,32 +218,32 @@ fn check_unused_imports(ast: &GoFile, path: &Path, issues: &mut Vec<Issue>) -> R
         }
     }
     let mut used_imports = HashSet::new();
-    let selector_nodes = ast.find_nodes("selector_expression ");
+    let selector_nodes = ast.find_nodes("selector_expression");
     for node in selector_nodes {
-        if let Some(operand) = node.child_by_field_name("operand ") {
+        if let Some(operand) = node.child_by_field_name("operand") {
             let package = ast.get_snippet(operand.start_byte(), operand.end_byte());
             used_imports.insert(package.trim().to_string());
         }
     }
-    let type_nodes = ast.find_nodes("type_identifier ");
+    let type_nodes = ast.find_nodes("type_identifier");
     for node in type_nodes {
-        if let Some(package) = node.child_by_field_name("package ") {
+        if let Some(package) = node.child_by_field_name("package") {
             let pkg_name = ast.get_snippet(package.start_byte(), package.end_byte());
             used_imports.insert(pkg_name.trim().to_string());
         }
     }
-    let qualified_nodes = ast.find_nodes("qualified_type ");
+    let qualified_nodes = ast.find_nodes("qualified_type");
     for node in qualified_nodes {
-        if let Some(package) = node.child_by_field_name("package ") {
+        if let Some(package) = node.child_by_field_name("package") {
             let pkg_name = ast.get_snippet(package.start_byte(), package.end_byte());
             used_imports.insert(pkg_name.trim().to_string());
         }
     }
-    let type_assertion_nodes = ast.find_nodes("type_assertion_expression ");
+    let type_assertion_nodes = ast.find_nodes("type_assertion_expression");
     for node in type_assertion_nodes {
-        if let Some(type_node) = node.child_by_field_name("type ") {
-            if type_node.kind() == "qualified_type " {
-                if let Some(package) = type_node.child_by_field_name("package ") {
+        if let Some(type_node) = node.child_by_field_name("type") {
+            if type_node.kind() == "qualified_type" {
+                if let Some(package) = type_node.child_by_field_name("package") {
                     let pkg_name = ast.get_snippet(package.start_byte(), package.end_byte());
                     used_imports.insert(pkg_name.trim().to_string());
                 }
@@ -83,9 +252,9 @@ fn check_unused_imports(ast: &GoFile, path: &Path, issues: &mut Vec<Issue>) -> R
     }
     for (package, (import_path, line, column, alias)) in imports {
         let is_used = if let Some(alias_val) = alias {
-            used_imports.contains(&alias_val.trim_matches('"').to_string())
+            used_imports.contains(alias_val.trim_matches('"'))
         } else {
-            used_imports.contains(&package.trim_matches('"').to_string())
+            used_imports.contains(package.trim_matches('"'))
         };
         
         if !is_used {
@@ -93,8 +262,9 @@ fn check_unused_imports(ast: &GoFile, path: &Path, issues: &mut Vec<Issue>) -> R
                 file_path: path.to_path_buf(),
                 line,
                 column,
-                issue_type: IssueType::DeadCode,
-                severity: Severity::Warning,
+                rule: Rule::UnusedImport,
+                issue_type: Rule::UnusedImport.issue_type(),
+                severity: Rule::UnusedImport.default_severity(),
                 message: format!("Unused import: {}", import_path),
                 code: import_path,
                 fix_available: true,
@@ -107,36 +277,33 @@ fn check_unused_imports(ast: &GoFile, path: &Path, issues: &mut Vec<Issue>) -> R
     Ok(())
 }
 
-fn check_unused_functions(ast: &GoFile, path: &Path, issues: &mut Vec<Issue>) -> Result<()> {
-    let function_nodes = ast.find_nodes("function_declaration ");
+fn check_unused_functions(ast: &GoFile, path: &Path, project: &Project, issues: &mut Vec<Issue>) -> Result<()> {
+    let function_nodes = ast.find_nodes("function_declaration");
     let mut functions = HashMap::new();
     for node in &function_nodes {
-        if let Some(name_node) = node.child_by_field_name("name ") {
+        if let Some(name_node) = node.child_by_field_name("name") {
             let func_name = ast.get_snippet(name_node.start_byte(), name_node.end_byte());
             let (line, column) = ast.get_position(node.start_byte());
-            if func_name == "main " || func_name == "init " {
+            if func_name == "main" || func_name == "init" {
                 continue;
             }
-            if func_name.chars().next().map_or(false, |c| c.is_uppercase()) {
+            if func_name.chars().next().is_some_and(|c| c.is_uppercase()) {
                 continue;
             }
             
             functions.insert(func_name.clone(), (line, column));
         }
     }
-    let call_nodes = ast.find_nodes("call_expression ");
-    let mut used_functions = HashSet::new();
-    
-    for node in call_nodes {
-        if let Some(function_node) = node.child_by_field_name("function ") {
-            if function_node.kind() == "identifier " {
-                let func_name = ast.get_snippet(function_node.start_byte(), function_node.end_byte());
-                used_functions.insert(func_name);
-            }
+    let file_references;
+    let used_functions = match project.package_references(path) {
+        Some(references) => references,
+        None => {
+            file_references = project::references(ast);
+            file_references.iter().map(String::as_str).collect()
         }
-    }
+    };
     for (func_name, (line, column)) in functions {
-        if !used_functions.contains(&func_name) {
+        if !used_functions.contains(func_name.as_str()) {
             let (start, end) = find_function_range(ast, &func_name);
             let func_snippet = if start < end {
                 ast.get_snippet(start, end)
@@ -147,8 +314,9 @@ fn check_unused_functions(ast: &GoFile, path: &Path, issues: &mut Vec<Issue>) ->
                 file_path: path.to_path_buf(),
                 line,
                 column,
-                issue_type: IssueType::DeadCode,
-                severity: Severity::Warning,
+                rule: Rule::UnusedFunction,
+                issue_type: Rule::UnusedFunction.issue_type(),
+                severity: Rule::UnusedFunction.default_severity(),
                 message: format!("Unused function: {}", func_name),
                 code: if func_snippet.len() > 100 {
                     func_name.clone()
@@ -165,73 +333,355 @@ fn check_unused_functions(ast: &GoFile, path: &Path, issues: &mut Vec<Issue>) ->
     Ok(())
 }
 
-fn check_unused_variables(ast: &GoFile, path: &Path, issues: &mut Vec<Issue>) -> Result<()> {
-    let var_nodes = ast.find_nodes("var_declaration ");
-    let short_var_nodes = ast.find_nodes("short_var_declaration ");
-    let mut variables = HashMap::new();
-    for node in &var_nodes {
-        let var_specs = ast.find_nodes("var_spec ")
-            .into_iter()
-            .filter(|n| n.parent().map_or(false, |p| p.id() == node.id()))
-            .collect::<Vec<_>>();
+fn check_unused_variables(
+    ast: &GoFile,
+    scopes: &ScopeTree,
+    path: &Path,
+    project: &Project,
+    issues: &mut Vec<Issue>,
+) -> Result<()> {
+    for binding in scopes.bindings.iter().filter(|b| b.kind == BindingKind::Var && b.uses == 0) {
+        let (line, column) = ast.get_position(binding.node.start_byte());
+        let issue = Issue {
+            file_path: path.to_path_buf(),
+            line,
+            column,
+            rule: Rule::UnusedVariable,
+            issue_type: Rule::UnusedVariable.issue_type(),
+            severity: Rule::UnusedVariable.default_severity(),
+            message: format!("Unused variable: {}", binding.name),
+            code: binding.name.clone(),
+            fix_available: fixes::removable_variable(binding.node, &ast.content).is_some(),
+        };
         
-        for spec in var_specs {
-            let names = spec.child_by_field_name("name ");
-            if let Some(name_list) = names {
-                for child in name_list.named_children(&mut name_list.walk()) {
-                    if child.kind() == "identifier " {
-                        let var_name = ast.get_snippet(child.start_byte(), child.end_byte());
-                        let (line, column) = ast.get_position(child.start_byte());
-                        if var_name != "_" {
-                            variables.insert(var_name.clone(), (line, column));
-                        }
-                    }
-                }
-            }
+        issues.push(issue);
+    }
+    
+    let file_references;
+    let package_references = match project.package_references(path) {
+        Some(references) => references,
+        None => {
+            file_references = project::references(ast);
+            file_references.iter().map(String::as_str).collect()
         }
+    };
+    let package_vars = project::declarations(ast)
+        .into_iter()
+        .filter(|d| d.kind == DeclarationKind::Var && !d.is_exported());
+    for declaration in package_vars {
+        if package_references.contains(declaration.name.as_str()) {
+            continue;
+        }
+        
+        let issue = Issue {
+            file_path: path.to_path_buf(),
+            line: declaration.line,
+            column: declaration.column,
+            rule: Rule::UnusedVariable,
+            issue_type: Rule::UnusedVariable.issue_type(),
+            severity: Rule::UnusedVariable.default_severity(),
+            message: format!("Unused package-level variable: {}", declaration.name),
+            code: declaration.name.clone(),
+            fix_available: false,
+        };
+        
+        issues.push(issue);
     }
-    for node in &short_var_nodes {
-        if let Some(left_node) = node.child_by_field_name("left ") {
-            for child in left_node.named_children(&mut left_node.walk()) {
-                if child.kind() == "identifier " {
-                    let var_name = ast.get_snippet(child.start_byte(), child.end_byte());
-                    let (line, column) = ast.get_position(child.start_byte());
-                    if var_name != "_" {
-                        variables.insert(var_name.clone(), (line, column));
-                    }
-                }
-            }
+    
+    Ok(())
+}
+
+fn check_unused_parameters(
+    ast: &GoFile,
+    scopes: &ScopeTree,
+    path: &Path,
+    project: &Project,
+    issues: &mut Vec<Issue>,
+) -> Result<()> {
+    let is_test = path.to_string_lossy().ends_with("_test.go");
+    for binding in &scopes.bindings {
+        let unused = match binding.kind {
+            BindingKind::Parameter => binding.uses == 0,
+            BindingKind::Result => binding.uses == 0 && binding.writes == 0,
+            _ => false,
+        };
+        let Some(function) = binding.function else {
+            continue;
+        };
+        if !unused || !requires_parameters(ast, function, project, is_test) {
+            continue;
         }
+        
+        let (line, column) = ast.get_position(binding.node.start_byte());
+        let message = if binding.kind == BindingKind::Parameter {
+            format!("Unused parameter: {}", binding.name)
+        } else {
+            format!("Unused named result: {}", binding.name)
+        };
+        let issue = Issue {
+            file_path: path.to_path_buf(),
+            line,
+            column,
+            rule: Rule::UnusedParameter,
+            issue_type: Rule::UnusedParameter.issue_type(),
+            severity: Rule::UnusedParameter.default_severity(),
+            message,
+            code: binding.name.clone(),
+            fix_available: true,
+        };
+        
+        issues.push(issue);
     }
-    let expr_nodes = ast.find_nodes("identifier ");
-    let mut used_vars = HashSet::new();
     
-    for node in expr_nodes {
-        let parent = node.parent();
-        if let Some(parent_node) = parent {
-            let parent_type = parent_node.kind();
-            if parent_type == "var_spec " && parent_node.child_by_field_name("name ") == Some(node) {
+    Ok(())
+}
+
+fn requires_parameters(ast: &GoFile, function: tree_sitter::Node, project: &Project, is_test: bool) -> bool {
+    if !matches!(function.kind(), "function_declaration" | "method_declaration") {
+        return false;
+    }
+    let Some(body) = function.child_by_field_name("body") else {
+        return false;
+    };
+    if body.named_child_count() == 0 {
+        return false;
+    }
+    let Some(name_node) = function.child_by_field_name("name") else {
+        return false;
+    };
+    
+    let name = ast.get_snippet(name_node.start_byte(), name_node.end_byte());
+    if project.value_references().contains(&name) {
+        return false;
+    }
+    if function.kind() == "method_declaration"
+        && (project.interface_methods().contains(&name) || IMPLICIT_INTERFACE_METHODS.contains(&name.as_str()))
+    {
+        return false;
+    }
+    if is_test && ["Test", "Benchmark", "Fuzz"].iter().any(|prefix| name.starts_with(prefix)) {
+        return false;
+    }
+    
+    true
+}
+
+fn check_unused_constants(ast: &GoFile, path: &Path, project: &Project, issues: &mut Vec<Issue>) -> Result<()> {
+    let Some(references) = project.package_references(path) else {
+        return Ok(());
+    };
+    let root = ast.tree.root_node();
+    let mut cursor = root.walk();
+    for declaration in root.named_children(&mut cursor).filter(|n| n.kind() == "const_declaration") {
+        let is_used = |name: &String| {
+            name.chars().next().is_some_and(char::is_uppercase) || references.contains(name.as_str())
+        };
+        
+        for spec_name in project::specs(declaration).into_iter().flat_map(project::spec_names) {
+            let name = ast.get_snippet(spec_name.start_byte(), spec_name.end_byte());
+            if name == "_" || is_used(&name) {
                 continue;
             }
-            if parent_type == "short_var_declaration " && parent_node.child_by_field_name("left ") == Some(node) {
+            
+            let (line, column) = ast.get_position(spec_name.start_byte());
+            let issue = Issue {
+                file_path: path.to_path_buf(),
+                line,
+                column,
+                rule: Rule::UnusedConstant,
+                issue_type: Rule::UnusedConstant.issue_type(),
+                severity: Rule::UnusedConstant.default_severity(),
+                message: format!("Unused constant: {}", name),
+                code: name,
+                fix_available: false,
+            };
+            
+            issues.push(issue);
+        }
+    }
+    
+    Ok(())
+}
+
+fn check_unused_types(ast: &GoFile, path: &Path, project: &Project, issues: &mut Vec<Issue>) -> Result<()> {
+    let Some(references) = project.package_references(path) else {
+        return Ok(());
+    };
+    let types = project::declarations(ast)
+        .into_iter()
+        .filter(|d| d.kind == DeclarationKind::Type && !d.is_exported() && d.name != "_");
+    for declaration in types {
+        if references.contains(declaration.name.as_str()) {
+            continue;
+        }
+        
+        let issue = Issue {
+            file_path: path.to_path_buf(),
+            line: declaration.line,
+            column: declaration.column,
+            rule: Rule::UnusedType,
+            issue_type: Rule::UnusedType.issue_type(),
+            severity: Rule::UnusedType.default_severity(),
+            message: format!("Unused type: {}", declaration.name),
+            code: declaration.name.clone(),
+            fix_available: false,
+        };
+        
+        issues.push(issue);
+    }
+    
+    Ok(())
+}
+
+fn check_unused_fields(ast: &GoFile, path: &Path, project: &Project, issues: &mut Vec<Issue>) -> Result<()> {
+    let Some(files) = project.package_files(path) else {
+        return Ok(());
+    };
+    let reflective = files
+        .iter()
+        .flat_map(|f| &f.imports)
+        .any(|import| REFLECTIVE_IMPORTS.contains(&import.path.as_str()));
+    if reflective {
+        return Ok(());
+    }
+    let mut used: HashSet<&str> = HashSet::new();
+    let mut unkeyed_literals: HashSet<&str> = HashSet::new();
+    for file in &files {
+        used.extend(file.member_references.iter().map(String::as_str));
+        used.extend(file.references.iter().map(String::as_str));
+        unkeyed_literals.extend(file.unkeyed_literals.iter().map(String::as_str));
+    }
+    
+    for type_spec in ast.find_nodes("type_spec") {
+        if type_spec.parent().and_then(|d| d.parent()).map(|p| p.kind()) != Some("source_file") {
+            continue;
+        }
+        let (Some(name_node), Some(struct_type)) = (type_spec.child_by_field_name("name"), type_spec.child_by_field_name("type")) else {
+            continue;
+        };
+        let type_name = ast.get_snippet(name_node.start_byte(), name_node.end_byte());
+        if struct_type.kind() != "struct_type" || unkeyed_literals.contains(type_name.as_str()) {
+            continue;
+        }
+        
+        let fields: Vec<tree_sitter::Node> = named_children(struct_type)
+            .into_iter()
+            .flat_map(named_children)
+            .filter(|f| f.kind() == "field_declaration")
+            .collect();
+        for field in fields {
+            if field.child_by_field_name("tag").is_some() {
                 continue;
             }
+            let mut cursor = field.walk();
+            let names: Vec<tree_sitter::Node> = field.children_by_field_name("name", &mut cursor).collect();
+            for field_name in names {
+                let name = ast.get_snippet(field_name.start_byte(), field_name.end_byte());
+                if name == "_" || name.chars().next().is_some_and(char::is_uppercase) || used.contains(name.as_str()) {
+                    continue;
+                }
+                
+                let (line, column) = ast.get_position(field_name.start_byte());
+                let issue = Issue {
+                    file_path: path.to_path_buf(),
+                    line,
+                    column,
+                    rule: Rule::UnusedField,
+                    issue_type: Rule::UnusedField.issue_type(),
+                    severity: Rule::UnusedField.default_severity(),
+                    message: format!("Unused field: {}.{}", type_name, name),
+                    code: format!("{}.{}", type_name, name),
+                    fix_available: false,
+                };
+                
+                issues.push(issue);
+            }
         }
+    }
+    
+    Ok(())
+}
+
+fn check_unused_methods(ast: &GoFile, path: &Path, project: &Project, issues: &mut Vec<Issue>) -> Result<()> {
+    let Some(files) = project.package_files(path) else {
+        return Ok(());
+    };
+    let members: HashSet<&str> = files.iter().flat_map(|f| f.member_references.iter().map(String::as_str)).collect();
+    let methods = project::declarations(ast)
+        .into_iter()
+        .filter(|d| d.kind == DeclarationKind::Method && !d.is_exported() && d.name != "_");
+    for declaration in methods {
+        if members.contains(declaration.name.as_str()) || project.interface_methods().contains(&declaration.name) {
+            continue;
+        }
+        
+        let issue = Issue {
+            file_path: path.to_path_buf(),
+            line: declaration.line,
+            column: declaration.column,
+            rule: Rule::UnusedMethod,
+            issue_type: Rule::UnusedMethod.issue_type(),
+            severity: Rule::UnusedMethod.default_severity(),
+            message: format!("Unused method: {}", declaration.qualified_name()),
+            code: declaration.qualified_name(),
+            fix_available: false,
+        };
         
-        let var_name = ast.get_snippet(node.start_byte(), node.end_byte());
-        used_vars.insert(var_name);
+        issues.push(issue);
     }
-    for (var_name, (line, column)) in variables {
-        if !used_vars.contains(&var_name) {
+    
+    Ok(())
+}
+
+fn check_unreachable_code(ast: &GoFile, path: &Path, issues: &mut Vec<Issue>) -> Result<()> {
+    let mut functions = ast.find_nodes("function_declaration");
+    functions.extend(ast.find_nodes("method_declaration"));
+    functions.extend(ast.find_nodes("func_literal"));
+    for function in functions {
+        let Some(body) = function.child_by_field_name("body") else {
+            continue;
+        };
+        for statement in Cfg::build(ast, body).unreachable_statements() {
+            let (line, column) = ast.get_position(statement.start_byte());
+            let snippet = ast.get_snippet(statement.start_byte(), statement.end_byte());
             let issue = Issue {
                 file_path: path.to_path_buf(),
                 line,
                 column,
-                issue_type: IssueType::DeadCode,
-                severity: Severity::Warning,
-                message: format!("Unused variable: {}", var_name),
-                code: var_name,
-                fix_available: true,
+                rule: Rule::UnreachableCode,
+                issue_type: Rule::UnreachableCode.issue_type(),
+                severity: Rule::UnreachableCode.default_severity(),
+                message: "Unreachable code".to_string(),
+                code: snippet.lines().next().unwrap_or_default().trim().to_string(),
+                fix_available: false,
+            };
+            
+            issues.push(issue);
+        }
+    }
+    
+    Ok(())
+}
+
+fn check_ineffectual_assignments(ast: &GoFile, scopes: &ScopeTree, path: &Path, issues: &mut Vec<Issue>) -> Result<()> {
+    let mut functions = ast.find_nodes("function_declaration");
+    functions.extend(ast.find_nodes("method_declaration"));
+    functions.extend(ast.find_nodes("func_literal"));
+    for function in functions {
+        for access in liveness::dead_stores(ast, scopes, function) {
+            let access = &scopes.accesses[access];
+            let name = &scopes.bindings[access.binding].name;
+            let (line, column) = ast.get_position(access.node.start_byte());
+            let issue = Issue {
+                file_path: path.to_path_buf(),
+                line,
+                column,
+                rule: Rule::IneffectualAssignment,
+                issue_type: Rule::IneffectualAssignment.issue_type(),
+                severity: Rule::IneffectualAssignment.default_severity(),
+                message: format!("Ineffectual assignment to {}: the value is never read", name),
+                code: name.clone(),
+                fix_available: false,
             };
             
             issues.push(issue);
@@ -248,10 +698,10 @@ fn extract_package_name(import_path: &str) -> String {
 }
 
 fn find_function_range(ast: &GoFile, func_name: &str) -> (usize, usize) {
-    let function_nodes = ast.find_nodes("function_declaration ");
+    let function_nodes = ast.find_nodes("function_declaration");
     
     for node in function_nodes {
-        if let Some(name_node) = node.child_by_field_name("name ") {
+        if let Some(name_node) = node.child_by_field_name("name") {
             let name = ast.get_snippet(name_node.start_byte(), name_node.end_byte());
             if name == func_name {
                 return (node.start_byte(), node.end_byte());
@@ -260,4 +710,209 @@ fn find_function_range(ast: &GoFile, func_name: &str) -> (usize, usize) {
     }
     
     (0, 0)
-} 
\ No newline at end of file
+} 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer;
+    use crate::test_support::TempDir;
+
+    fn findings(files: &[(&str, &str)], rule: Rule) -> Vec<String> {
+        findings_with(files, rule, &Config::default())
+    }
+
+    fn findings_with(files: &[(&str, &str)], rule: Rule, config: &Config) -> Vec<String> {
+        let dir = TempDir::new("dead-code");
+        dir.write("go.mod", "module example.com/m\n");
+        for (name, content) in files {
+            dir.write(name, content);
+        }
+        let mut found: Vec<String> = analyzer::run_analysis(dir.path(), config, None)
+            .unwrap()
+            .into_iter()
+            .filter(|issue| issue.rule == rule)
+            .map(|issue| issue.message)
+            .collect();
+        found.sort();
+        found
+    }
+
+    #[test]
+    fn functions_used_from_another_file_are_not_reported() {
+        let found = findings(
+            &[
+                ("p/a.go", "package p\n\nfunc helper() {}\n\nfunc orphan() {}\n"),
+                ("p/b.go", "package p\n\nfunc Run() {\n\thelper()\n}\n"),
+            ],
+            Rule::UnusedFunction,
+        );
+        assert_eq!(found, ["Unused function: orphan"]);
+    }
+
+    #[test]
+    fn function_values_and_go_and_defer_calls_count_as_uses() {
+        let found = findings(
+            &[(
+                "p/p.go",
+                "package p\n\nfunc handler() {}\n\nfunc worker() {}\n\nfunc cleanup() {}\n\nfunc Run() func() {\n\tgo worker()\n\tdefer cleanup()\n\treturn handler\n}\n",
+            )],
+            Rule::UnusedFunction,
+        );
+        assert!(found.is_empty(), "{:?}", found);
+    }
+
+    #[test]
+    fn main_and_init_are_entry_points() {
+        let found = findings(
+            &[("cmd/main.go", "package main\n\nfunc init() {}\n\nfunc main() {}\n")],
+            Rule::UnusedFunction,
+        );
+        assert!(found.is_empty(), "{:?}", found);
+    }
+
+    #[test]
+    fn reports_exported_identifiers_unused_across_the_module() {
+        let mut config = Config::default();
+        config.general.ignore_patterns.clear();
+        config.rules.dead_code.detect_unused_exported = true;
+        config.rules.dead_code.api_roots = vec!["client.*".to_string(), "Server.Serve".to_string()];
+        let files = [
+            ("cmd/main.go", "package main\n\nimport \"example.com/m/store\"\n\nfunc main() {\n\tstore.Open()\n}\n"),
+            (
+                "store/store.go",
+                "package store\n\ntype Server struct{}\n\nfunc (Server) Serve() {}\n\nfunc (Server) String() string { return \"\" }\n\nfunc Open() {}\n\nfunc Close() {}\n\nfunc Helper() {}\n",
+            ),
+            (
+                "store/store_test.go",
+                "package store\n\nimport \"testing\"\n\nfunc NewFixture() {}\n\nfunc TestOpen(t *testing.T) {\n\tHelper()\n}\n",
+            ),
+            ("client/client.go", "package client\n\nfunc Dial() {}\n"),
+        ];
+        assert_eq!(
+            findings_with(&files, Rule::UnusedExported, &config),
+            ["Exported function Close is never used in the module"]
+        );
+
+        config.rules.dead_code.detect_unused_exported = false;
+        assert!(findings_with(&files, Rule::UnusedExported, &config).is_empty());
+    }
+
+    #[test]
+    fn reports_unread_parameters_and_named_results() {
+        let found = findings(
+            &[(
+                "p/p.go",
+                "package p\n\nfunc Sum(a, b int) (total int, err error) {\n\ttotal = a\n\treturn\n}\n",
+            )],
+            Rule::UnusedParameter,
+        );
+        assert_eq!(found, ["Unused named result: err", "Unused parameter: b"]);
+    }
+
+    #[test]
+    fn interface_methods_and_typed_func_values_keep_their_parameters() {
+        let found = findings(
+            &[
+                (
+                    "p/iface.go",
+                    "package p\n\ntype Handler interface {\n\tHandle(name string, n int) error\n}\n",
+                ),
+                (
+                    "p/impl.go",
+                    "package p\n\ntype quiet struct{}\n\nfunc (quiet) Handle(name string, n int) error {\n\treturn nil\n}\n\nfunc (quiet) String() string {\n\treturn \"quiet\"\n}\n",
+                ),
+                (
+                    "p/hooks.go",
+                    "package p\n\nvar onExit func(code int) = exitHook\n\nfunc exitHook(code int) {\n\tprintln(\"bye\")\n}\n",
+                ),
+            ],
+            Rule::UnusedParameter,
+        );
+        assert!(found.is_empty(), "{:?}", found);
+    }
+
+    #[test]
+    fn blank_parameters_and_empty_stubs_are_not_reported() {
+        let found = findings(
+            &[(
+                "p/p.go",
+                "package p\n\nfunc Ignore(_ int, s string) {\n\tprintln(s)\n}\n\nfunc Stub(a int, b string) {}\n",
+            )],
+            Rule::UnusedParameter,
+        );
+        assert!(found.is_empty(), "{:?}", found);
+    }
+
+    #[test]
+    fn reports_unused_constants_and_types() {
+        let files = [(
+            "p/p.go",
+            "package p\n\nconst (\n\tlimit = 10\n\tstale = 20\n\tMax = 30\n)\n\nconst (\n\tred = iota\n\tgreen\n)\n\ntype used struct{}\n\ntype orphan int\n\nfunc Run() used {\n\tprintln(limit, red)\n\treturn used{}\n}\n",
+        )];
+        assert_eq!(findings(&files, Rule::UnusedConstant), ["Unused constant: green", "Unused constant: stale"]);
+        assert_eq!(findings(&files, Rule::UnusedType), ["Unused type: orphan"]);
+    }
+
+    #[test]
+    fn reports_iota_members_individually() {
+        let found = findings(
+            &[(
+                "p/p.go",
+                "package p\n\ntype level int\n\nconst (\n\t_ level = iota\n\tdebug\n\tinfo\n\twarn\n)\n\nfunc Default() level {\n\treturn info\n}\n",
+            )],
+            Rule::UnusedConstant,
+        );
+        assert_eq!(found, ["Unused constant: debug", "Unused constant: warn"]);
+    }
+
+    #[test]
+    fn tagged_fields_and_reflective_packages_keep_their_fields() {
+        let found = findings(
+            &[(
+                "p/p.go",
+                "package p\n\ntype config struct {\n\tname string `json:\"name\"`\n\tport int\n\tdebug bool\n}\n\nfunc Port(c config) int {\n\treturn c.port\n}\n",
+            )],
+            Rule::UnusedField,
+        );
+        assert_eq!(found, ["Unused field: config.debug"]);
+
+        let found = findings(
+            &[
+                ("p/p.go", "package p\n\ntype header struct {\n\tmagic uint32\n}\n\nvar Header header\n"),
+                ("p/decode.go", "package p\n\nimport _ \"encoding/binary\"\n"),
+            ],
+            Rule::UnusedField,
+        );
+        assert!(found.is_empty(), "{:?}", found);
+    }
+
+    #[test]
+    fn methods_satisfying_an_interface_are_used() {
+        let found = findings(
+            &[(
+                "p/p.go",
+                "package p\n\ntype closer interface {\n\tclose()\n}\n\ntype file struct{}\n\nfunc (file) close() {}\n\nfunc (file) flush() {}\n\nfunc (file) sync() {}\n\nfunc Flush(f file) {\n\tf.flush()\n}\n",
+            )],
+            Rule::UnusedMethod,
+        );
+        assert_eq!(found, ["Unused method: file.sync"]);
+    }
+
+    #[test]
+    fn only_side_effect_free_variables_are_auto_fixable() {
+        let dir = TempDir::new("dead-code-fixable");
+        dir.write("go.mod", "module example.com/m\n");
+        dir.write(
+            "p/p.go",
+            "package p\n\nfunc compute() int { return 1 }\n\nfunc Run() {\n\tlimit := 10\n\tresult := compute()\n}\n",
+        );
+        let fixable: Vec<(String, bool)> = analyzer::run_analysis(dir.path(), &Config::default(), None)
+            .unwrap()
+            .into_iter()
+            .filter(|issue| issue.rule == Rule::UnusedVariable)
+            .map(|issue| (issue.code, issue.fix_available))
+            .collect();
+        assert_eq!(fixable, [("limit".to_string(), true), ("result".to_string(), false)]);
+    }
+}
+
@@ -0,0 +1,315 @@
+use anyhow::Result;
+use std::collections::HashMap;
+use std::path::Path;
+use tree_sitter::Node;
+
+use crate::analyzer::scope::{AccessKind, Binding, ScopeTree};
+use crate::analyzer::{Issue, Rule};
+use crate::config::Config;
+use crate::glob::Glob;
+use crate::parser::{named_children, GoFile};
+use crate::project::{self, ErrorSignature, Project};
+
+struct Resolver<'a> {
+    ast: &'a GoFile,
+    path: &'a Path,
+    project: &'a Project,
+    package: &'a str,
+    qualifiers: HashMap<&'a str, &'a str>,
+    scopes: &'a ScopeTree<'a>,
+    locals: HashMap<usize, usize>,
+}
+
+pub fn analyze<'a>(
+    ast: &'a GoFile,
+    scopes: &'a ScopeTree<'a>,
+    path: &'a Path,
+    project: &Project,
+    config: &Config,
+    issues: &mut Vec<Issue>,
+) -> Result<()> {
+    let rules = &config.rules.errors;
+    if !rules.detect_unchecked_errors && !rules.detect_discarded_errors {
+        return Ok(());
+    }
+    let (Some(package), Some(file)) = (project.package_of(path), project.source_file(path)) else {
+        return Ok(());
+    };
+    let excludes = rules
+        .exclude
+        .iter()
+        .map(|name| Glob::new(&project::signature_key(name)))
+        .collect::<Result<Vec<_>>>()?;
+
+    let locals = scopes
+        .accesses
+        .iter()
+        .filter(|access| access.kind == AccessKind::Read)
+        .map(|access| (access.node.id(), access.binding))
+        .collect();
+    let resolver = Resolver {
+        ast,
+        path,
+        project,
+        package: &package.path,
+        qualifiers: project.import_qualifiers(file),
+        scopes,
+        locals,
+    };
+
+    for call in ast.find_nodes("call_expression") {
+        let Some(parent) = call.parent() else {
+            continue;
+        };
+        let rule = match parent.kind() {
+            "expression_statement" if rules.detect_unchecked_errors => Rule::UncheckedError,
+            "expression_list" if rules.detect_discarded_errors => Rule::DiscardedError,
+            _ => continue,
+        };
+        let Some(signature) = resolver.signature(call) else {
+            continue;
+        };
+        let key = project::signature_key(&signature.name);
+        if excludes.iter().any(|glob| glob.is_match(&key)) {
+            continue;
+        }
+        if rule == Rule::DiscardedError && !discards_error(ast, parent, &signature) {
+            continue;
+        }
+
+        let (line, column) = ast.get_position(call.start_byte());
+        let snippet = ast.get_snippet(call.start_byte(), call.end_byte());
+        let message = match rule {
+            Rule::UncheckedError => format!("Error return value of {} is not checked", signature.name),
+            _ => format!("Error return value of {} is assigned to _", signature.name),
+        };
+        let issue = Issue {
+            file_path: path.to_path_buf(),
+            line,
+            column,
+            rule,
+            issue_type: rule.issue_type(),
+            severity: rule.default_severity(),
+            message,
+            code: snippet.lines().next().unwrap_or_default().to_string(),
+            fix_available: false,
+        };
+
+        issues.push(issue);
+    }
+
+    Ok(())
+}
+
+fn discards_error(ast: &GoFile, right: Node, signature: &ErrorSignature) -> bool {
+    let Some(statement) = right.parent() else {
+        return false;
+    };
+    if !matches!(statement.kind(), "assignment_statement" | "short_var_declaration")
+        || statement.child_by_field_name("right") != Some(right)
+        || right.named_child_count() != 1
+    {
+        return false;
+    }
+    let Some(left) = statement.child_by_field_name("left") else {
+        return false;
+    };
+    let targets = named_children(left);
+    let position = signature.position.unwrap_or(targets.len().saturating_sub(1));
+    targets
+        .get(position)
+        .is_some_and(|target| ast.get_snippet(target.start_byte(), target.end_byte()) == "_")
+}
+
+impl<'a> Resolver<'a> {
+    fn signature(&self, call: Node) -> Option<ErrorSignature> {
+        let function = call.child_by_field_name("function")?;
+        match function.kind() {
+            "identifier" if !self.locals.contains_key(&function.id()) => {
+                self.project.error_signature(self.path, self.package, None, &self.snippet(function))
+            }
+            "selector_expression" => {
+                let operand = function.child_by_field_name("operand")?;
+                let name = self.snippet(function.child_by_field_name("field")?);
+                if operand.kind() != "identifier" {
+                    return None;
+                }
+                match self.locals.get(&operand.id()) {
+                    Some(&binding) => {
+                        let (package, type_name) = self.binding_type(&self.scopes.bindings[binding])?;
+                        self.project.error_signature(self.path, package, Some(&type_name), &name)
+                    }
+                    None => {
+                        let package = self.qualifiers.get(self.snippet(operand).as_str())?;
+                        self.project.error_signature(self.path, package, None, &name)
+                    }
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn binding_type(&self, binding: &Binding) -> Option<(&'a str, String)> {
+        let parent = binding.node.parent()?;
+        match parent.kind() {
+            "parameter_declaration" | "variadic_parameter_declaration" => {
+                self.named_type(parent.child_by_field_name("type")?)
+            }
+            "var_spec" => match parent.child_by_field_name("type") {
+                Some(type_node) => self.named_type(type_node),
+                None => {
+                    let index = spec_position(parent, binding.node)?;
+                    self.assigned_type(parent.child_by_field_name("value")?, index)
+                }
+            },
+            "expression_list" => {
+                let statement = parent.parent()?;
+                if statement.kind() != "short_var_declaration" {
+                    return None;
+                }
+                let index = named_children(parent).iter().position(|n| *n == binding.node)?;
+                self.assigned_type(statement.child_by_field_name("right")?, index)
+            }
+            _ => None,
+        }
+    }
+
+    fn assigned_type(&self, values: Node, index: usize) -> Option<(&'a str, String)> {
+        let values = named_children(values);
+        match values.as_slice() {
+            [call] if call.kind() == "call_expression" => self.result_type(*call, index),
+            _ => self.value_type(*values.get(index)?),
+        }
+    }
+
+    fn value_type(&self, value: Node) -> Option<(&'a str, String)> {
+        match value.kind() {
+            "composite_literal" => self.named_type(value.child_by_field_name("type")?),
+            "unary_expression" => self.value_type(value.child_by_field_name("operand")?),
+            "call_expression" => self.result_type(value, 0),
+            _ => None,
+        }
+    }
+
+    fn result_type(&self, call: Node, index: usize) -> Option<(&'a str, String)> {
+        let function = call.child_by_field_name("function")?;
+        if function.kind() != "selector_expression" {
+            return None;
+        }
+        let operand = function.child_by_field_name("operand")?;
+        let name = self.snippet(function.child_by_field_name("field")?);
+        if operand.kind() != "identifier" {
+            return None;
+        }
+        let symbol = match self.locals.get(&operand.id()) {
+            Some(&binding) => {
+                let (package, type_name) = self.binding_type(&self.scopes.bindings[binding])?;
+                self.project.stdlib_symbol(self.path, package, Some(&type_name), &name)?
+            }
+            None => {
+                let package = self.qualifiers.get(self.snippet(operand).as_str())?;
+                self.project.stdlib_symbol(self.path, package, None, &name)?
+            }
+        };
+        let result = symbol.results.get(index)?;
+        let (package, type_name) = result.strip_prefix('*').unwrap_or(result).rsplit_once('.')?;
+        if !package.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '/' | '_' | '.' | '-')) {
+            return None;
+        }
+        Some((package, type_name.to_string()))
+    }
+
+    fn named_type(&self, type_node: Node) -> Option<(&'a str, String)> {
+        match type_node.kind() {
+            "pointer_type" => self.named_type(type_node.named_child(0)?),
+            "generic_type" => self.named_type(type_node.child_by_field_name("type")?),
+            "type_identifier" => Some((self.package, self.snippet(type_node))),
+            "qualified_type" => {
+                let package = self.snippet(type_node.child_by_field_name("package")?);
+                let package = self.qualifiers.get(package.as_str())?;
+                Some((package, self.snippet(type_node.child_by_field_name("name")?)))
+            }
+            _ => None,
+        }
+    }
+
+    fn snippet(&self, node: Node) -> String {
+        self.ast.get_snippet(node.start_byte(), node.end_byte())
+    }
+}
+
+fn spec_position(spec: Node, name: Node) -> Option<usize> {
+    project::spec_names(spec).iter().position(|n| *n == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser;
+    use crate::test_support::TempDir;
+
+    fn check(name: &str, source: &str) -> Vec<(usize, Rule)> {
+        check_with_go(name, "1.22", source)
+    }
+
+    fn check_with_go(name: &str, go_version: &str, source: &str) -> Vec<(usize, Rule)> {
+        let dir = TempDir::new(name);
+        dir.write("go.mod", &format!("module example.com/m\n\ngo {}\n", go_version));
+        let file = dir.write("p.go", &format!("package p\n\nimport \"os\"\n\n{}\n", source));
+
+        let project = Project::build(std::slice::from_ref(&file));
+        let ast = parser::parse_file(&file).unwrap();
+        let scopes = ScopeTree::build(&ast);
+        let mut issues = Vec::new();
+        analyze(&ast, &scopes, &file, &project, &Config::default(), &mut issues).unwrap();
+
+        issues.into_iter().map(|issue| (issue.line, issue.rule)).collect()
+    }
+
+    #[test]
+    fn reports_unchecked_calls() {
+        let source = "func f() {\n\tos.Remove(\"x\")\n\tos.Chdir(\"x\")\n\tif err := os.Mkdir(\"x\", 0o755); err != nil {\n\t\treturn\n\t}\n}";
+        let issues = check("unchecked", source);
+        assert_eq!(issues, [(6, Rule::UncheckedError), (7, Rule::UncheckedError)]);
+    }
+
+    #[test]
+    fn stdlib_signatures_follow_the_module_go_version() {
+        let source = "import \"io\"\n\nfunc f(r io.Reader) {\n\tio.ReadAll(r)\n\tos.Remove(\"x\")\n}";
+        assert_eq!(check_with_go("go-new", "1.22", source), [(8, Rule::UncheckedError), (9, Rule::UncheckedError)]);
+        assert_eq!(check_with_go("go-old", "1.15", source), [(9, Rule::UncheckedError)]);
+    }
+
+    #[test]
+    fn resolves_methods_on_stdlib_values() {
+        let source = "func f() {\n\tfile, err := os.Open(\"x\")\n\tif err != nil {\n\t\treturn\n\t}\n\tfile.Close()\n}";
+        let issues = check("methods", source);
+        assert_eq!(issues, [(10, Rule::UncheckedError)]);
+    }
+
+    #[test]
+    fn reports_blank_only_at_the_error_position() {
+        let source = "func two() (error, int) { return nil, 0 }\n\n\
+            func three() (int, error, string) { return 0, nil, \"\" }\n\n\
+            func f() {\n\
+            \t_, a := two()\n\
+            \terr, _ := two()\n\
+            \tb, _, c := three()\n\
+            \t_, err, _ = three()\n\
+            \tfile, _ := os.Open(\"x\")\n\
+            \t_, err = os.Open(\"x\")\n\
+            \t_ = os.Remove(\"x\")\n\
+            \tprintln(a, b, c, err, file)\n\
+            }";
+        let issues = check("blank", source);
+        assert_eq!(
+            issues,
+            [
+                (10, Rule::DiscardedError),
+                (12, Rule::DiscardedError),
+                (14, Rule::DiscardedError),
+                (16, Rule::DiscardedError),
+            ]
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::HashSet;
+use tree_sitter::Node;
+
+use crate::analyzer::cfg::Cfg;
+use crate::analyzer::scope::{AccessKind, BindingKind, ScopeTree};
+use crate::parser::GoFile;
+
+#[derive(Debug, Default)]
+struct Effects {
+    uses: HashSet<usize>,
+    defs: Vec<(usize, usize)>,
+}
+
+pub fn dead_stores(ast: &GoFile, scopes: &ScopeTree, function: Node) -> Vec<usize> {
+    let Some(body) = function.child_by_field_name("body") else {
+        return Vec::new();
+    };
+    let cfg = Cfg::build(ast, body);
+
+    let escaped: HashSet<usize> = scopes
+        .accesses
+        .iter()
+        .filter(|access| access.function != scopes.bindings[access.binding].function || is_address_taken(access.node))
+        .map(|access| access.binding)
+        .collect();
+    let mut local: Vec<usize> = (0..scopes.accesses.len())
+        .filter(|&index| {
+            let access = &scopes.accesses[index];
+            access.function == Some(function) && !escaped.contains(&access.binding)
+        })
+        .collect();
+    local.sort_by_key(|&index| scopes.accesses[index].node.start_byte());
+    let results: Vec<usize> = (0..scopes.bindings.len())
+        .filter(|&index| {
+            let binding = &scopes.bindings[index];
+            binding.kind == BindingKind::Result && binding.function == Some(function)
+        })
+        .collect();
+
+    let effects: Vec<Vec<Effects>> = cfg
+        .blocks
+        .iter()
+        .map(|block| {
+            block
+                .nodes
+                .iter()
+                .map(|node| effects(scopes, &local, &results, *node))
+                .collect()
+        })
+        .collect();
+
+    let mut live_in: Vec<HashSet<usize>> = vec![HashSet::new(); cfg.blocks.len()];
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for block in (0..cfg.blocks.len()).rev() {
+            let mut live = live_out(&cfg, &live_in, block);
+            for effect in effects[block].iter().rev() {
+                transfer(&mut live, effect);
+            }
+            if live != live_in[block] {
+                live_in[block] = live;
+                changed = true;
+            }
+        }
+    }
+
+    let reachable = cfg.reachable();
+    let mut dead = Vec::new();
+    for block in (0..cfg.blocks.len()).filter(|&block| reachable[block]) {
+        let mut live = live_out(&cfg, &live_in, block);
+        for effect in effects[block].iter().rev() {
+            for &(binding, access) in &effect.defs {
+                if !live.contains(&binding) && is_reportable(scopes, access) {
+                    dead.push(access);
+                }
+            }
+            transfer(&mut live, effect);
+        }
+    }
+    dead.sort_by_key(|&access| scopes.accesses[access].node.start_byte());
+    dead
+}
+
+fn effects(scopes: &ScopeTree, local: &[usize], results: &[usize], node: Node) -> Effects {
+    let start = local.partition_point(|&index| scopes.accesses[index].node.start_byte() < node.start_byte());
+    let end = local.partition_point(|&index| scopes.accesses[index].node.start_byte() < node.end_byte());
+
+    let mut effects = Effects::default();
+    for &index in &local[start..end] {
+        let access = &scopes.accesses[index];
+        match access.kind {
+            AccessKind::Read => {
+                effects.uses.insert(access.binding);
+            }
+            AccessKind::Write | AccessKind::Declare => {
+                if reads_before_write(access.node) {
+                    effects.uses.insert(access.binding);
+                }
+                effects.defs.push((access.binding, index));
+            }
+        }
+    }
+    if node.kind() == "return_statement" && node.named_child_count() == 0 {
+        effects.uses.extend(results.iter().copied());
+    }
+    effects
+}
+
+fn live_out(cfg: &Cfg, live_in: &[HashSet<usize>], block: usize) -> HashSet<usize> {
+    cfg.blocks[block]
+        .successors
+        .iter()
+        .flat_map(|&successor| live_in[successor].iter().copied())
+        .collect()
+}
+
+fn transfer(live: &mut HashSet<usize>, effect: &Effects) {
+    for (binding, _) in &effect.defs {
+        live.remove(binding);
+    }
+    live.extend(effect.uses.iter().copied());
+}
+
+fn is_reportable(scopes: &ScopeTree, access: usize) -> bool {
+    let access = &scopes.accesses[access];
+    let binding = &scopes.bindings[access.binding];
+    if binding.uses == 0 || !matches!(binding.kind, BindingKind::Var | BindingKind::Parameter | BindingKind::Result) {
+        return false;
+    }
+    let Some(parent) = access.node.parent() else {
+        return false;
+    };
+    match (access.kind, parent.kind()) {
+        (_, "inc_statement" | "dec_statement") => true,
+        (_, "var_spec") => parent.child_by_field_name("value").is_some(),
+        (_, "expression_list") => parent
+            .parent()
+            .is_some_and(|statement| matches!(statement.kind(), "assignment_statement" | "short_var_declaration")),
+        _ => false,
+    }
+}
+
+fn reads_before_write(node: Node) -> bool {
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+    match parent.kind() {
+        "inc_statement" | "dec_statement" => true,
+        "expression_list" => parent.parent().is_some_and(|statement| {
+            statement.kind() == "assignment_statement"
+                && statement.child_by_field_name("operator").is_some_and(|operator| operator.kind() != "=")
+        }),
+        _ => false,
+    }
+}
+
+fn is_address_taken(node: Node) -> bool {
+    let mut current = node;
+    while let Some(parent) = current.parent() {
+        match parent.kind() {
+            "selector_expression" | "index_expression" if parent.child_by_field_name("operand") == Some(current) => {
+                current = parent;
+            }
+            "parenthesized_expression" => current = parent,
+            "unary_expression" => {
+                return parent.child_by_field_name("operator").is_some_and(|operator| operator.kind() == "&");
+            }
+            _ => return false,
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::parse;
+
+    fn dead_lines(source: &str) -> Vec<usize> {
+        let ast = parse(&format!("package p\n\n{}\n", source));
+        let scopes = ScopeTree::build(&ast);
+        let function = ast.find_nodes("function_declaration")[0];
+        dead_stores(&ast, &scopes, function)
+            .into_iter()
+            .map(|access| ast.get_position(scopes.accesses[access].node.start_byte()).0)
+            .collect()
+    }
+
+    #[test]
+    fn reports_overwritten_stores() {
+        assert_eq!(dead_lines("func f() {\n\tx := 1\n\tx = 2\n\tprintln(x)\n}"), [4]);
+        assert_eq!(dead_lines("func f() {\n\tx := 1\n\tx += 2\n\tx = 5\n\tprintln(x)\n}"), [5]);
+        assert!(dead_lines("func f() {\n\tx := 1\n\tx += 2\n\tprintln(x)\n}").is_empty());
+    }
+
+    #[test]
+    fn stores_read_by_the_next_iteration_are_live() {
+        let source = "func f() {\n\tx := 0\n\tfor i := 0; i < 3; i++ {\n\t\tprintln(x)\n\t\tx = i\n\t}\n}";
+        assert!(dead_lines(source).is_empty());
+    }
+
+    #[test]
+    fn naked_return_reads_named_results() {
+        let naked = "func f() (n int) {\n\tn = 1\n\tif n > 0 {\n\t\tn = 2\n\t\treturn\n\t}\n\treturn 3\n}";
+        assert!(dead_lines(naked).is_empty());
+
+        let explicit = "func f() (n int) {\n\tn = 1\n\tif n > 0 {\n\t\tn = 2\n\t\treturn 3\n\t}\n\treturn 3\n}";
+        assert_eq!(dead_lines(explicit), [6]);
+    }
+
+    #[test]
+    fn skips_address_taken_and_captured_variables() {
+        let address = "func f() {\n\tx := 1\n\tp := &x\n\tx = 2\n\t*p = 3\n\tprintln(x)\n}";
+        assert!(dead_lines(address).is_empty());
+
+        let captured = "func f() {\n\tx := 1\n\tg := func() { println(x) }\n\tx = 2\n\tg()\n}";
+        assert!(dead_lines(captured).is_empty());
+    }
+}
@@ -1,23 +1,37 @@
 use anyhow::Result;
 use colored::Colorize;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
 use crate::config::Config;
 use crate::parser;
+use crate::project::{self, Project};
 
 mod syntax;
 mod dead_code;
 mod style;
 mod architecture;
+mod errors;
+mod rules;
+mod cfg;
+mod liveness;
+mod scope;
+mod suppress;
 
-#[derive(Debug, Clone)]
+pub use rules::Rule;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum IssueType {
     Syntax,
     DeadCode,
     Style,
     Architecture,
+    ErrorHandling,
 }
 
 impl fmt::Display for IssueType {
@@ -27,11 +41,13 @@ impl fmt::Display for IssueType {
             IssueType::DeadCode => write!(f, "DeadCode "),
             IssueType::Style => write!(f, "Style "),
             IssueType::Architecture => write!(f, "Architecture "),
+            IssueType::ErrorHandling => write!(f, "ErrorHandling "),
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "snake_case")]
 pub enum Severity {
     Error,
     Warning,
@@ -49,7 +65,19 @@ impl fmt::Display for Severity {
 }
 
 impl Severity {
-    pub fn to_colored_string(&self) -> colored::ColoredString {
+    fn rank(self) -> u8 {
+        match self {
+            Severity::Error => 2,
+            Severity::Warning => 1,
+            Severity::Info => 0,
+        }
+    }
+    
+    pub fn is_at_least(self, threshold: Severity) -> bool {
+        self.rank() >= threshold.rank()
+    }
+    
+    pub fn to_colored_string(self) -> colored::ColoredString {
         match self {
             Severity::Error => "ERROR ".red().bold(),
             Severity::Warning => "WARNING ".yellow().bold(),
@@ -58,11 +86,13 @@ impl Severity {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Issue {
+    #[serde(rename = "file")]
     pub file_path: PathBuf,
     pub line: usize,
     pub column: usize,
+    pub rule: Rule,
     pub issue_type: IssueType,
     pub severity: Severity,
     pub message: String,
@@ -78,9 +108,10 @@ impl Issue {
             self.column
         ).bold();
         
-        println!("{} [{}]: {} (at {})",
+        println!("{} [{}] [{}]: {} (at {})",
             self.severity.to_colored_string(),
             self.issue_type.to_string().cyan(),
+            self.rule.to_string().magenta(),
             self.message,
             location,
         );
@@ -95,31 +126,90 @@ impl Issue {
     }
 }
 
-pub fn run_analysis(path: &Path, config: &Config) -> Result<Vec<Issue>> {
-    let mut issues = Vec::new();
+pub fn run_analysis(path: &Path, config: &Config, jobs: Option<usize>) -> Result<Vec<Issue>> {
     if !path.exists() {
         return Err(anyhow::anyhow!("Path does not exist: {}", path.display()));
     }
+    let files = collect_files(path, config)?;
+    let forbidden = architecture::compile_forbidden_imports(&config.rules.architecture)?;
+    let pool = thread_pool(jobs)?;
+    let per_file = pool.install(|| -> Result<Vec<Vec<Issue>>> {
+        let project = Project::build(&project_files(path, &files, config)?);
+        let mut project_issues = analyze_project(&project, &files, config)?;
+        let work: Vec<(&PathBuf, Vec<Issue>)> = files
+            .iter()
+            .map(|file| (file, project_issues.remove(&project::canonical(file)).unwrap_or_default()))
+            .collect();
+        
+        work.into_par_iter()
+            .map(|(file, extra)| analyze_file(file, &project, config, &forbidden, extra))
+            .collect()
+    })?;
+    
+    let mut issues: Vec<Issue> = per_file.into_iter().flatten().collect();
+    issues.sort_by(|a, b| {
+        a.file_path
+            .cmp(&b.file_path)
+            .then(a.line.cmp(&b.line))
+            .then(a.column.cmp(&b.column))
+            .then(a.rule.cmp(&b.rule))
+            .then_with(|| a.message.cmp(&b.message))
+    });
+    
+    Ok(issues)
+}
+
+pub fn build_project(path: &Path, config: &Config, jobs: Option<usize>) -> Result<Project> {
+    if !path.exists() {
+        return Err(anyhow::anyhow!("Path does not exist: {}", path.display()));
+    }
+    let files = collect_files(path, config)?;
+    let pool = thread_pool(jobs)?;
+    pool.install(|| Ok(Project::build(&project_files(path, &files, config)?)))
+}
+
+fn thread_pool(jobs: Option<usize>) -> Result<rayon::ThreadPool> {
+    let mut pool = rayon::ThreadPoolBuilder::new();
+    if let Some(jobs) = jobs {
+        pool = pool.num_threads(jobs);
+    }
+    Ok(pool.build()?)
+}
+
+fn project_files(path: &Path, files: &[PathBuf], config: &Config) -> Result<Vec<PathBuf>> {
+    let root = project::find_project_root(path);
+    let roots = project::source_roots(&root);
+    if roots == [project::canonical(path)] {
+        return Ok(files.to_vec());
+    }
+    
+    let mut project_files = Vec::new();
+    for source_root in &roots {
+        project_files.extend(collect_files(source_root, config)?);
+    }
+    Ok(project_files)
+}
+
+fn collect_files(path: &Path, config: &Config) -> Result<Vec<PathBuf>> {
     if path.is_file() {
-        if is_go_file(path) {
-            analyze_file(path, config, &mut issues)?;
-        }
-        return Ok(issues);
+        return Ok(if is_go_file(path) { vec![path.to_path_buf()] } else { Vec::new() });
     }
+    
+    let mut files = Vec::new();
     for entry in WalkDir::new(path).follow_links(true) {
         let entry = entry?;
         let path = entry.path();
         
         if path.is_file() && is_go_file(path) && !is_excluded(path, config) {
-            analyze_file(path, config, &mut issues)?;
+            files.push(path.to_path_buf());
         }
     }
     
-    Ok(issues)
+    Ok(files)
 }
 
 fn is_go_file(path: &Path) -> bool {
-    path.extension().map_or(false, |ext| ext == "go ")
+    path.extension().is_some_and(|ext| ext == "go")
 }
 
 fn is_excluded(path: &Path, config: &Config) -> bool {
@@ -142,23 +232,93 @@ fn is_excluded(path: &Path, config: &Config) -> bool {
     false
 }
 
-fn analyze_file(path: &Path, config: &Config, issues: &mut Vec<Issue>) -> Result<()> {
+fn analyze_project(
+    project: &Project,
+    files: &[PathBuf],
+    config: &Config,
+) -> Result<HashMap<PathBuf, Vec<Issue>>> {
+    let targets: HashSet<PathBuf> = files.iter().map(|file| project::canonical(file)).collect();
+    let mut issues = Vec::new();
+    if config.rules.dead_code.enabled {
+        dead_code::analyze_project(project, &targets, config, &mut issues)?;
+    }
+    
+    if config.rules.architecture.enabled {
+        architecture::analyze_project(project, &targets, config, &mut issues)?;
+    }
+    
+    let mut by_file: HashMap<PathBuf, Vec<Issue>> = HashMap::new();
+    for issue in issues {
+        by_file.entry(issue.file_path.clone()).or_default().push(issue);
+    }
+    
+    Ok(by_file)
+}
+
+fn analyze_file(
+    path: &Path,
+    project: &Project,
+    config: &Config,
+    forbidden: &[architecture::Forbidden],
+    project_issues: Vec<Issue>,
+) -> Result<Vec<Issue>> {
     let ast = parser::parse_file(path)?;
+    let scopes = scope::ScopeTree::build(&ast);
+    let mut file_issues = Vec::new();
     if config.rules.syntax.enabled {
-        syntax::analyze(&ast, path, config, issues)?;
+        syntax::analyze(&ast, path, config, &mut file_issues)?;
     }
     
     if config.rules.dead_code.enabled {
-        dead_code::analyze(&ast, path, config, issues)?;
+        dead_code::analyze(&ast, &scopes, path, project, config, &mut file_issues)?;
     }
     
     if config.rules.style.enabled {
-        style::analyze(&ast, path, config, issues)?;
+        style::analyze(&ast, path, config, &mut file_issues)?;
     }
     
     if config.rules.architecture.enabled {
-        architecture::analyze(&ast, path, config, issues)?;
+        architecture::analyze(&ast, path, project, config, forbidden, &mut file_issues)?;
+    }
+    
+    if config.rules.errors.enabled {
+        errors::analyze(&ast, &scopes, path, project, config, &mut file_issues)?;
     }
     
-    Ok(())
-} 
\ No newline at end of file
+    file_issues.extend(project_issues.into_iter().map(|issue| Issue {
+        file_path: path.to_path_buf(),
+        ..issue
+    }));
+    
+    Ok(suppress::apply(&ast, path, config, file_issues))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    #[test]
+    fn output_order_does_not_depend_on_jobs() {
+        let dir = TempDir::new("jobs");
+        for package in ["a", "b", "c"] {
+            for file in 0..4 {
+                let source = format!(
+                    "package {}\n\nimport \"os\"\n\nfunc helper{}() {{\n    x_y := 1\n}}\n",
+                    package, file
+                );
+                dir.write(&format!("{}/f{}.go", package, file), &source);
+            }
+        }
+
+        let key = |issue: &Issue| (issue.file_path.clone(), issue.line, issue.column, issue.rule);
+        let serial: Vec<_> = run_analysis(dir.path(), &Config::default(), Some(1)).unwrap().iter().map(key).collect();
+        assert!(serial.len() >= 12 * 3);
+        assert!(serial.windows(2).all(|pair| pair[0] <= pair[1]));
+        for _ in 0..2 {
+            let parallel: Vec<_> =
+                run_analysis(dir.path(), &Config::default(), Some(8)).unwrap().iter().map(key).collect();
+            assert_eq!(parallel, serial);
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use serde::{Serialize, Serializer};
+use std::fmt;
+
+use crate::analyzer::{IssueType, Severity};
+use crate::config::Config;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Rule {
+    UnusedImport,
+    UnusedFunction,
+    UnusedVariable,
+    UnusedSuppression,
+    UnusedExported,
+    UnusedParameter,
+    UnusedConstant,
+    UnusedType,
+    UnusedField,
+    UnusedMethod,
+    UnreachableCode,
+    IneffectualAssignment,
+    SyntaxError,
+    LineTooLong,
+    CamelCase,
+    ControlStatementSpacing,
+    BraceStyle,
+    TabIndentation,
+    PackageBoundary,
+    InternalImport,
+    CircularDependency,
+    UnstableDependency,
+    ForbiddenImport,
+    UncheckedError,
+    DiscardedError,
+}
+
+impl Rule {
+    pub const ALL: &'static [Rule] = &[
+        Rule::UnusedImport,
+        Rule::UnusedFunction,
+        Rule::UnusedVariable,
+        Rule::UnusedSuppression,
+        Rule::UnusedExported,
+        Rule::UnusedParameter,
+        Rule::UnusedConstant,
+        Rule::UnusedType,
+        Rule::UnusedField,
+        Rule::UnusedMethod,
+        Rule::UnreachableCode,
+        Rule::IneffectualAssignment,
+        Rule::SyntaxError,
+        Rule::LineTooLong,
+        Rule::CamelCase,
+        Rule::ControlStatementSpacing,
+        Rule::BraceStyle,
+        Rule::TabIndentation,
+        Rule::PackageBoundary,
+        Rule::InternalImport,
+        Rule::CircularDependency,
+        Rule::UnstableDependency,
+        Rule::ForbiddenImport,
+        Rule::UncheckedError,
+        Rule::DiscardedError,
+    ];
+
+    pub fn id(self) -> &'static str {
+        match self {
+            Rule::UnusedImport => "DX1001",
+            Rule::UnusedFunction => "DX1002",
+            Rule::UnusedVariable => "DX1003",
+            Rule::UnusedSuppression => "DX1004",
+            Rule::UnusedExported => "DX1005",
+            Rule::UnusedParameter => "DX1006",
+            Rule::UnusedConstant => "DX1007",
+            Rule::UnusedType => "DX1008",
+            Rule::UnusedField => "DX1009",
+            Rule::UnusedMethod => "DX1010",
+            Rule::UnreachableCode => "DX1011",
+            Rule::IneffectualAssignment => "DX1012",
+            Rule::SyntaxError => "DX2001",
+            Rule::LineTooLong => "DX2002",
+            Rule::CamelCase => "DX3001",
+            Rule::ControlStatementSpacing => "DX3002",
+            Rule::BraceStyle => "DX3003",
+            Rule::TabIndentation => "DX3004",
+            Rule::PackageBoundary => "DX4001",
+            Rule::InternalImport => "DX4002",
+            Rule::CircularDependency => "DX4003",
+            Rule::UnstableDependency => "DX4004",
+            Rule::ForbiddenImport => "DX4005",
+            Rule::UncheckedError => "DX5001",
+            Rule::DiscardedError => "DX5002",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Rule::UnusedImport => "unused-import",
+            Rule::UnusedFunction => "unused-function",
+            Rule::UnusedVariable => "unused-variable",
+            Rule::UnusedSuppression => "unused-suppression",
+            Rule::UnusedExported => "unused-exported",
+            Rule::UnusedParameter => "unused-parameter",
+            Rule::UnusedConstant => "unused-constant",
+            Rule::UnusedType => "unused-type",
+            Rule::UnusedField => "unused-field",
+            Rule::UnusedMethod => "unused-method",
+            Rule::UnreachableCode => "unreachable-code",
+            Rule::IneffectualAssignment => "ineffectual-assignment",
+            Rule::SyntaxError => "syntax-error",
+            Rule::LineTooLong => "line-too-long",
+            Rule::CamelCase => "camel-case",
+            Rule::ControlStatementSpacing => "control-statement-spacing",
+            Rule::BraceStyle => "brace-style",
+            Rule::TabIndentation => "tab-indentation",
+            Rule::PackageBoundary => "package-boundary",
+            Rule::InternalImport => "internal-import",
+            Rule::CircularDependency => "circular-dependency",
+            Rule::UnstableDependency => "unstable-dependency",
+            Rule::ForbiddenImport => "forbidden-import",
+            Rule::UncheckedError => "unchecked-error",
+            Rule::DiscardedError => "discarded-error",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Rule::UnusedImport => "Imported package is never referenced",
+            Rule::UnusedFunction => "Unexported function is never called",
+            Rule::UnusedVariable => "Variable is declared but never used",
+            Rule::UnusedSuppression => "Suppression directive does not silence any finding",
+            Rule::UnusedExported => "Exported identifier is never referenced in the module",
+            Rule::UnusedParameter => "Function parameter or named result is never used",
+            Rule::UnusedConstant => "Unexported constant is never used",
+            Rule::UnusedType => "Unexported type is never used",
+            Rule::UnusedField => "Unexported struct field is never used",
+            Rule::UnusedMethod => "Unexported method is never called",
+            Rule::UnreachableCode => "Statement can never be executed",
+            Rule::IneffectualAssignment => "Assigned value is never read",
+            Rule::SyntaxError => "The file could not be parsed as valid Go",
+            Rule::LineTooLong => "Line exceeds the configured maximum length",
+            Rule::CamelCase => "Identifier should be camelCase instead of snake_case",
+            Rule::ControlStatementSpacing => "Control statement keyword should be followed by a space",
+            Rule::BraceStyle => "Opening brace should be on the same line as the declaration",
+            Rule::TabIndentation => "Go code should be indented with tabs",
+            Rule::PackageBoundary => "Import crosses a package or architecture layer boundary",
+            Rule::InternalImport => "Import of an internal package from outside its tree",
+            Rule::CircularDependency => "Packages import each other in a cycle",
+            Rule::UnstableDependency => "Heavily depended-upon package is unstable",
+            Rule::ForbiddenImport => "Import path is banned by the configuration",
+            Rule::UncheckedError => "Error returned by a call is never checked",
+            Rule::DiscardedError => "Error returned by a call is assigned to the blank identifier",
+        }
+    }
+
+    pub fn issue_type(self) -> IssueType {
+        match self {
+            Rule::UnusedImport
+            | Rule::UnusedFunction
+            | Rule::UnusedVariable
+            | Rule::UnusedSuppression
+            | Rule::UnusedExported
+            | Rule::UnusedParameter
+            | Rule::UnusedConstant
+            | Rule::UnusedType
+            | Rule::UnusedField
+            | Rule::UnusedMethod
+            | Rule::UnreachableCode
+            | Rule::IneffectualAssignment => IssueType::DeadCode,
+            Rule::SyntaxError | Rule::LineTooLong => IssueType::Syntax,
+            Rule::CamelCase | Rule::ControlStatementSpacing | Rule::BraceStyle | Rule::TabIndentation => {
+                IssueType::Style
+            }
+            Rule::PackageBoundary
+            | Rule::InternalImport
+            | Rule::CircularDependency
+            | Rule::UnstableDependency
+            | Rule::ForbiddenImport => IssueType::Architecture,
+            Rule::UncheckedError | Rule::DiscardedError => IssueType::ErrorHandling,
+        }
+    }
+
+    pub fn default_severity(self) -> Severity {
+        match self {
+            Rule::SyntaxError | Rule::InternalImport | Rule::CircularDependency | Rule::ForbiddenImport => {
+                Severity::Error
+            }
+            Rule::UnusedImport
+            | Rule::UnusedFunction
+            | Rule::UnusedVariable
+            | Rule::UnusedSuppression
+            | Rule::UnusedExported
+            | Rule::UnusedParameter
+            | Rule::UnusedConstant
+            | Rule::UnusedType
+            | Rule::UnusedField
+            | Rule::UnusedMethod
+            | Rule::UnreachableCode
+            | Rule::IneffectualAssignment
+            | Rule::LineTooLong
+            | Rule::PackageBoundary
+            | Rule::UnstableDependency
+            | Rule::UncheckedError
+            | Rule::DiscardedError => Severity::Warning,
+            Rule::CamelCase | Rule::ControlStatementSpacing | Rule::BraceStyle | Rule::TabIndentation => {
+                Severity::Info
+            }
+        }
+    }
+
+    pub fn is_enabled(self, config: &Config) -> bool {
+        let rules = &config.rules;
+        match self {
+            Rule::UnusedImport => rules.dead_code.enabled && rules.dead_code.detect_unused_imports,
+            Rule::UnusedFunction => rules.dead_code.enabled && rules.dead_code.detect_unused_functions,
+            Rule::UnusedVariable => rules.dead_code.enabled && rules.dead_code.detect_unused_variables,
+            Rule::UnusedSuppression => rules.dead_code.enabled && rules.dead_code.detect_unused_suppressions,
+            Rule::UnusedExported => rules.dead_code.enabled && rules.dead_code.detect_unused_exported,
+            Rule::UnusedParameter => rules.dead_code.enabled && rules.dead_code.detect_unused_parameters,
+            Rule::UnusedConstant => rules.dead_code.enabled && rules.dead_code.detect_unused_constants,
+            Rule::UnusedType => rules.dead_code.enabled && rules.dead_code.detect_unused_types,
+            Rule::UnusedField => rules.dead_code.enabled && rules.dead_code.detect_unused_fields,
+            Rule::UnusedMethod => rules.dead_code.enabled && rules.dead_code.detect_unused_methods,
+            Rule::UnreachableCode => rules.dead_code.enabled && rules.dead_code.detect_unreachable_code,
+            Rule::IneffectualAssignment => {
+                rules.dead_code.enabled && rules.dead_code.detect_ineffectual_assignments
+            }
+            Rule::SyntaxError => rules.syntax.enabled,
+            Rule::LineTooLong => rules.syntax.enabled && rules.syntax.max_line_length > 0,
+            Rule::CamelCase => rules.style.enabled && rules.style.enforce_camel_case,
+            Rule::ControlStatementSpacing => rules.style.enabled && rules.style.space_after_control_statements,
+            Rule::BraceStyle | Rule::TabIndentation => rules.style.enabled && rules.style.enforce_consistent_naming,
+            Rule::PackageBoundary | Rule::InternalImport => {
+                rules.architecture.enabled && rules.architecture.enforce_package_boundaries
+            }
+            Rule::CircularDependency => rules.architecture.enabled && rules.architecture.detect_circular_dependencies,
+            Rule::UnstableDependency => rules.architecture.enabled && rules.architecture.max_instability.is_some(),
+            Rule::ForbiddenImport => rules.architecture.enabled && !rules.architecture.forbidden_imports.is_empty(),
+            Rule::UncheckedError => rules.errors.enabled && rules.errors.detect_unchecked_errors,
+            Rule::DiscardedError => rules.errors.enabled && rules.errors.detect_discarded_errors,
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Rule> {
+        Rule::ALL
+            .iter()
+            .copied()
+            .find(|rule| rule.id().eq_ignore_ascii_case(code) || rule.name().eq_ignore_ascii_case(code))
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.id(), self.name())
+    }
+}
+
+impl Serialize for Rule {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.id())
+    }
+}
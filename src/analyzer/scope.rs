@@ -0,0 +1,372 @@
+use std::collections::HashMap;
+use tree_sitter::Node;
+
+use crate::parser::{named_children, GoFile};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BindingKind {
+    Var,
+    Const,
+    Parameter,
+    Result,
+    Receiver,
+}
+
+#[derive(Debug, Clone)]
+pub struct Binding<'a> {
+    pub name: String,
+    pub kind: BindingKind,
+    pub node: Node<'a>,
+    pub function: Option<Node<'a>>,
+    pub uses: usize,
+    pub writes: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessKind {
+    Declare,
+    Read,
+    Write,
+}
+
+#[derive(Debug, Clone)]
+pub struct Access<'a> {
+    pub node: Node<'a>,
+    pub binding: usize,
+    pub kind: AccessKind,
+    pub function: Option<Node<'a>>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Scope {
+    names: HashMap<String, usize>,
+}
+
+#[derive(Debug, Default)]
+pub struct ScopeTree<'a> {
+    pub scopes: Vec<Scope>,
+    pub bindings: Vec<Binding<'a>>,
+    pub accesses: Vec<Access<'a>>,
+    stack: Vec<usize>,
+    functions: Vec<Node<'a>>,
+}
+
+impl<'a> ScopeTree<'a> {
+    pub fn build(ast: &'a GoFile) -> ScopeTree<'a> {
+        let mut tree = ScopeTree::default();
+        let root = ast.tree.root_node();
+        tree.push();
+        let mut cursor = root.walk();
+        for node in root.named_children(&mut cursor) {
+            match node.kind() {
+                "function_declaration" | "method_declaration" => tree.walk_function(ast, node),
+                "var_declaration" | "const_declaration" => {
+                    for spec in named_children(node) {
+                        if let Some(value) = spec.child_by_field_name("value") {
+                            tree.walk(ast, value);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        tree.stack.clear();
+        tree
+    }
+
+    fn push(&mut self) {
+        self.scopes.push(Scope::default());
+        self.stack.push(self.scopes.len() - 1);
+    }
+
+    fn pop(&mut self) {
+        self.stack.pop();
+    }
+
+    fn current(&self) -> usize {
+        *self.stack.last().unwrap_or(&0)
+    }
+
+    fn declare(&mut self, ast: &GoFile, node: Node<'a>, kind: BindingKind) {
+        let name = ast.get_snippet(node.start_byte(), node.end_byte());
+        if name == "_" {
+            return;
+        }
+        let scope = self.current();
+        self.bindings.push(Binding {
+            name: name.clone(),
+            kind,
+            node,
+            function: self.functions.last().copied(),
+            uses: 0,
+            writes: 0,
+        });
+        let index = self.bindings.len() - 1;
+        self.scopes[scope].names.insert(name, index);
+        self.record(node, index, AccessKind::Declare);
+    }
+
+    fn record(&mut self, node: Node<'a>, binding: usize, kind: AccessKind) {
+        self.accesses.push(Access {
+            node,
+            binding,
+            kind,
+            function: self.functions.last().copied(),
+        });
+    }
+
+    fn resolve(&self, name: &str) -> Option<usize> {
+        self.stack
+            .iter()
+            .rev()
+            .find_map(|&scope| self.scopes[scope].names.get(name).copied())
+    }
+
+    fn use_identifier(&mut self, ast: &GoFile, node: Node<'a>) {
+        let name = ast.get_snippet(node.start_byte(), node.end_byte());
+        if let Some(index) = self.resolve(&name) {
+            self.bindings[index].uses += 1;
+            self.record(node, index, AccessKind::Read);
+        }
+    }
+
+    fn write_identifier(&mut self, ast: &GoFile, node: Node<'a>) {
+        let name = ast.get_snippet(node.start_byte(), node.end_byte());
+        if let Some(index) = self.resolve(&name) {
+            self.bindings[index].writes += 1;
+            self.record(node, index, AccessKind::Write);
+        }
+    }
+
+    fn walk_function(&mut self, ast: &GoFile, node: Node<'a>) {
+        self.push();
+        self.functions.push(node);
+        if let Some(receiver) = node.child_by_field_name("receiver") {
+            self.declare_parameters(ast, receiver, BindingKind::Receiver);
+        }
+        if let Some(parameters) = node.child_by_field_name("parameters") {
+            self.declare_parameters(ast, parameters, BindingKind::Parameter);
+        }
+        if let Some(result) = node.child_by_field_name("result") {
+            if result.kind() == "parameter_list" {
+                self.declare_parameters(ast, result, BindingKind::Result);
+            }
+        }
+        if let Some(body) = node.child_by_field_name("body") {
+            for statement in named_children(body) {
+                self.walk(ast, statement);
+            }
+        }
+        self.functions.pop();
+        self.pop();
+    }
+
+    fn declare_parameters(&mut self, ast: &GoFile, list: Node<'a>, kind: BindingKind) {
+        for parameter in named_children(list) {
+            let mut cursor = parameter.walk();
+            let names: Vec<Node> = parameter.children_by_field_name("name", &mut cursor).collect();
+            for name in names {
+                self.declare(ast, name, kind);
+            }
+        }
+    }
+
+    fn walk(&mut self, ast: &GoFile, node: Node<'a>) {
+        match node.kind() {
+            "identifier" => self.use_identifier(ast, node),
+            "func_literal" => self.walk_function(ast, node),
+            "function_type" | "type_declaration" | "parameter_list" | "field_identifier" => {}
+            "block" | "if_statement" | "for_statement" | "expression_switch_statement" | "select_statement"
+            | "expression_case" | "type_case" | "default_case" | "communication_case" => {
+                self.push();
+                self.walk_children(ast, node);
+                self.pop();
+            }
+            "type_switch_statement" => {
+                self.push();
+                if let Some(initializer) = node.child_by_field_name("initializer") {
+                    self.walk(ast, initializer);
+                }
+                if let Some(value) = node.child_by_field_name("value") {
+                    self.walk(ast, value);
+                }
+                if let Some(alias) = node.child_by_field_name("alias") {
+                    for name in named_children(alias).into_iter().filter(|n| n.kind() == "identifier") {
+                        self.declare(ast, name, BindingKind::Var);
+                    }
+                }
+                for clause in named_children(node)
+                    .into_iter()
+                    .filter(|n| matches!(n.kind(), "type_case" | "default_case"))
+                {
+                    self.walk(ast, clause);
+                }
+                self.pop();
+            }
+            "var_declaration" | "const_declaration" => {
+                let kind = if node.kind() == "var_declaration" {
+                    BindingKind::Var
+                } else {
+                    BindingKind::Const
+                };
+                for spec in named_children(node) {
+                    if let Some(value) = spec.child_by_field_name("value") {
+                        self.walk(ast, value);
+                    }
+                    let mut cursor = spec.walk();
+                    let names: Vec<Node> = spec.children_by_field_name("name", &mut cursor).collect();
+                    for name in names {
+                        self.declare(ast, name, kind);
+                    }
+                }
+            }
+            "short_var_declaration" | "range_clause" | "receive_statement" => {
+                if let Some(right) = node.child_by_field_name("right") {
+                    self.walk(ast, right);
+                }
+                if let Some(left) = node.child_by_field_name("left") {
+                    if node.kind() == "short_var_declaration" || has_token(node, ":=") {
+                        self.declare_or_assign(ast, left);
+                    } else {
+                        self.assign(ast, left);
+                    }
+                }
+            }
+            "assignment_statement" => {
+                if let Some(right) = node.child_by_field_name("right") {
+                    self.walk(ast, right);
+                }
+                if let Some(left) = node.child_by_field_name("left") {
+                    self.assign(ast, left);
+                }
+            }
+            "inc_statement" | "dec_statement" => self.assign(ast, node),
+            _ => self.walk_children(ast, node),
+        }
+    }
+
+    fn walk_children(&mut self, ast: &GoFile, node: Node<'a>) {
+        for child in named_children(node) {
+            self.walk(ast, child);
+        }
+    }
+
+    fn declare_or_assign(&mut self, ast: &GoFile, left: Node<'a>) {
+        let scope = self.current();
+        for target in named_children(left) {
+            if target.kind() != "identifier" {
+                self.walk(ast, target);
+                continue;
+            }
+            let name = ast.get_snippet(target.start_byte(), target.end_byte());
+            if self.scopes[scope].names.contains_key(&name) {
+                self.write_identifier(ast, target);
+            } else {
+                self.declare(ast, target, BindingKind::Var);
+            }
+        }
+    }
+
+    fn assign(&mut self, ast: &GoFile, left: Node<'a>) {
+        for target in named_children(left) {
+            if target.kind() == "identifier" {
+                self.write_identifier(ast, target);
+            } else {
+                self.walk(ast, target);
+            }
+        }
+    }
+}
+
+fn has_token(node: Node, token: &str) -> bool {
+    let mut cursor = node.walk();
+    let found = node.children(&mut cursor).any(|child| child.kind() == token);
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::parse;
+
+    fn named<'t, 'a>(scopes: &'t ScopeTree<'a>, name: &str) -> Vec<&'t Binding<'a>> {
+        scopes.bindings.iter().filter(|binding| binding.name == name).collect()
+    }
+
+    #[test]
+    fn shadowed_names_get_their_own_binding() {
+        let ast = parse(
+            "package p\n\nfunc f() int {\n\tx := 1\n\tif x > 0 {\n\t\tx := 2\n\t\treturn x\n\t}\n\treturn x\n}\n",
+        );
+        let scopes = ScopeTree::build(&ast);
+        let xs = named(&scopes, "x");
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].uses, 2);
+        assert_eq!(xs[1].uses, 1);
+        assert!(xs.iter().all(|binding| binding.kind == BindingKind::Var));
+    }
+
+    #[test]
+    fn short_redeclaration_writes_existing_binding() {
+        let ast = parse(
+            "package p\n\nfunc f() {\n\ta, err := g()\n\tb, err := g()\n\tprintln(a, b, err)\n}\n\nfunc g() (int, error) { return 0, nil }\n",
+        );
+        let scopes = ScopeTree::build(&ast);
+        let errs = named(&scopes, "err");
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].writes, 1);
+        assert_eq!(errs[0].uses, 1);
+    }
+
+    #[test]
+    fn closures_record_the_accessing_function() {
+        let ast = parse(
+            "package p\n\nfunc f() {\n\tn := 0\n\tinc := func() { n++ }\n\tinc()\n\tprintln(n)\n}\n",
+        );
+        let scopes = ScopeTree::build(&ast);
+        let n = scopes.bindings.iter().position(|binding| binding.name == "n").unwrap();
+        assert_eq!(scopes.bindings[n].writes, 1);
+        assert_eq!(scopes.bindings[n].uses, 1);
+
+        let declared_in = scopes.bindings[n].function.unwrap();
+        assert_eq!(declared_in.kind(), "function_declaration");
+        let write = scopes
+            .accesses
+            .iter()
+            .find(|access| access.binding == n && access.kind == AccessKind::Write)
+            .unwrap();
+        assert_eq!(write.function.unwrap().kind(), "func_literal");
+    }
+
+    #[test]
+    fn declares_signature_names_by_kind() {
+        let ast = parse("package p\n\ntype T struct{}\n\nfunc (t T) m(a, _ int) (n int, err error) {\n\treturn\n}\n");
+        let scopes = ScopeTree::build(&ast);
+        let kinds: Vec<(&str, BindingKind)> = scopes
+            .bindings
+            .iter()
+            .map(|binding| (binding.name.as_str(), binding.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                ("t", BindingKind::Receiver),
+                ("a", BindingKind::Parameter),
+                ("n", BindingKind::Result),
+                ("err", BindingKind::Result),
+            ]
+        );
+    }
+
+    #[test]
+    fn type_switch_alias_is_scoped_to_the_switch() {
+        let ast = parse(
+            "package p\n\nfunc f(x any) {\n\tswitch v := x.(type) {\n\tcase int:\n\t\tprintln(v)\n\tdefault:\n\t\tprintln(v)\n\t}\n}\n",
+        );
+        let scopes = ScopeTree::build(&ast);
+        let vs = named(&scopes, "v");
+        assert_eq!(vs.len(), 1);
+        assert_eq!(vs[0].uses, 2);
+        assert_eq!(named(&scopes, "x")[0].uses, 1);
+    }
+}
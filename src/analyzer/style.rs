@@ -2,7 +2,7 @@ use anyhow::Result;
 use regex::Regex;
 use std::path::Path;
 
-use crate::analyzer::{Issue, IssueType, Severity};
+use crate::analyzer::{Issue, Rule};
 use crate::config::Config;
 use crate::parser::GoFile;
 
@@ -25,27 +25,27 @@ fn check_naming_conventions(ast: &GoFile, path: &Path, config: &Config, issues:
 }
 
 fn check_var_naming(ast: &GoFile, path: &Path, issues: &mut Vec<Issue>) -> Result<()> {
-    let var_nodes = ast.find_nodes("var_declaration ");
-    let short_var_nodes = ast.find_nodes("short_var_declaration ");
+    let var_nodes = ast.find_nodes("var_declaration");
+    let short_var_nodes = ast.find_nodes("short_var_declaration");
     let snake_case_regex = Regex::new(r"\b[a-z]+_[a-z][a-z0-9]*\b").unwrap();
     for node in var_nodes {
-        let var_specs = ast.find_nodes("var_spec ")
+        let var_specs = ast.find_nodes("var_spec")
             .into_iter()
-            .filter(|n| n.parent().map_or(false, |p| p.id() == node.id()))
+            .filter(|n| n.parent().is_some_and(|p| p.id() == node.id()))
             .collect::<Vec<_>>();
         
         for spec in var_specs {
-            let names = spec.child_by_field_name("name ");
+            let names = spec.child_by_field_name("name");
             if let Some(name_list) = names {
                 for child in name_list.named_children(&mut name_list.walk()) {
-                    if child.kind() == "identifier " {
+                    if child.kind() == "identifier" {
                         check_identifier_naming(
                             ast,
                             child,
                             path,
                             issues,
                             &snake_case_regex,
-                            "variable ",
+                            "variable",
                         )?;
                     }
                 }
@@ -53,16 +53,16 @@ fn check_var_naming(ast: &GoFile, path: &Path, issues: &mut Vec<Issue>) -> Resul
         }
     }
     for node in short_var_nodes {
-        if let Some(left) = node.child_by_field_name("left ") {
+        if let Some(left) = node.child_by_field_name("left") {
             for child in left.named_children(&mut left.walk()) {
-                if child.kind() == "identifier " {
+                if child.kind() == "identifier" {
                     check_identifier_naming(
                         ast,
                         child,
                         path,
                         issues,
                         &snake_case_regex,
-                        "variable ",
+                        "variable",
                     )?;
                 }
             }
@@ -73,18 +73,18 @@ fn check_var_naming(ast: &GoFile, path: &Path, issues: &mut Vec<Issue>) -> Resul
 }
 
 fn check_func_naming(ast: &GoFile, path: &Path, issues: &mut Vec<Issue>) -> Result<()> {
-    let function_nodes = ast.find_nodes("function_declaration ");
+    let function_nodes = ast.find_nodes("function_declaration");
     let snake_case_regex = Regex::new(r"\b[a-z]+_[a-z][a-z0-9]*\b").unwrap();
     
     for node in function_nodes {
-        if let Some(name) = node.child_by_field_name("name ") {
+        if let Some(name) = node.child_by_field_name("name") {
             check_identifier_naming(
                 ast,
                 name,
                 path,
                 issues,
                 &snake_case_regex,
-                "function ",
+                "function",
             )?;
         }
     }
@@ -102,7 +102,7 @@ fn check_identifier_naming(
 ) -> Result<()> {
     let name = ast.get_snippet(node.start_byte(), node.end_byte());
     let (line, column) = ast.get_position(node.start_byte());
-    if name.chars().next().map_or(false, |c| c.is_uppercase()) {
+    if name.chars().next().is_some_and(|c| c.is_uppercase()) {
         return Ok(());
     }
     if name.len() <= 2 {
@@ -113,8 +113,9 @@ fn check_identifier_naming(
             file_path: path.to_path_buf(),
             line,
             column,
-            issue_type: IssueType::Style,
-            severity: Severity::Info,
+            rule: Rule::CamelCase,
+            issue_type: Rule::CamelCase.issue_type(),
+            severity: Rule::CamelCase.default_severity(),
             message: format!("{} name should be camelCase: {}", identifier_type, name),
             code: name.to_string(),
             fix_available: true,
@@ -130,17 +131,17 @@ fn check_control_statement_spacing(ast: &GoFile, path: &Path, config: &Config, i
     if !config.rules.style.space_after_control_statements {
         return Ok(());
     }
-    let if_nodes = ast.find_nodes("if_statement ");
-    let for_nodes = ast.find_nodes("for_statement ");
-    let switch_nodes = ast.find_nodes("switch_statement ");
+    let if_nodes = ast.find_nodes("if_statement");
+    let for_nodes = ast.find_nodes("for_statement");
+    let switch_nodes = ast.find_nodes("switch_statement");
     for node in if_nodes {
-        check_control_statement_space(ast, node, "if ", path, issues)?;
+        check_control_statement_space(ast, node, "if", path, issues)?;
     }
     for node in for_nodes {
-        check_control_statement_space(ast, node, "for ", path, issues)?;
+        check_control_statement_space(ast, node, "for", path, issues)?;
     }
     for node in switch_nodes {
-        check_control_statement_space(ast, node, "switch ", path, issues)?;
+        check_control_statement_space(ast, node, "switch", path, issues)?;
     }
     
     Ok(())
@@ -161,8 +162,9 @@ fn check_control_statement_space(
             file_path: path.to_path_buf(),
             line,
             column,
-            issue_type: IssueType::Style,
-            severity: Severity::Info,
+            rule: Rule::ControlStatementSpacing,
+            issue_type: Rule::ControlStatementSpacing.issue_type(),
+            severity: Rule::ControlStatementSpacing.default_severity(),
             message: format!("missing space after control statement: {}", keyword),
             code: line_content.to_string(),
             fix_available: true,
@@ -185,21 +187,21 @@ fn check_consistent_style(ast: &GoFile, path: &Path, config: &Config, issues: &m
 }
 
 fn check_brace_style(ast: &GoFile, path: &Path, issues: &mut Vec<Issue>) -> Result<()> {
-    let function_decls = ast.find_nodes("function_declaration ");
-    let if_statements = ast.find_nodes("if_statement ");
-    let for_statements = ast.find_nodes("for_statement ");
+    let function_decls = ast.find_nodes("function_declaration");
+    let if_statements = ast.find_nodes("if_statement");
+    let for_statements = ast.find_nodes("for_statement");
     for node in function_decls {
-        if let Some(body) = node.child_by_field_name("body ") {
-            check_node_brace_style(ast, node, body, "function ", path, issues)?;
+        if let Some(body) = node.child_by_field_name("body") {
+            check_node_brace_style(ast, node, body, "function", path, issues)?;
         }
     }
     for node in if_statements {
-        if let Some(consequence) = node.child_by_field_name("consequence ") {
+        if let Some(consequence) = node.child_by_field_name("consequence") {
             check_node_brace_style(ast, node, consequence, "if statement ", path, issues)?;
         }
     }
     for node in for_statements {
-        if let Some(body) = node.child_by_field_name("body ") {
+        if let Some(body) = node.child_by_field_name("body") {
             check_node_brace_style(ast, node, body, "for loop ", path, issues)?;
         }
     }
@@ -225,8 +227,9 @@ fn check_node_brace_style(
             file_path: path.to_path_buf(),
             line,
             column,
-            issue_type: IssueType::Style,
-            severity: Severity::Info,
+            rule: Rule::BraceStyle,
+            issue_type: Rule::BraceStyle.issue_type(),
+            severity: Rule::BraceStyle.default_severity(),
             message: format!("Opening brace should be on the same line as {} declaration ", node_type),
             code: line_content.to_string(),
             fix_available: false,
@@ -250,8 +253,9 @@ fn check_indentation(ast: &GoFile, path: &Path, issues: &mut Vec<Issue>) -> Resu
                 file_path: path.to_path_buf(),
                 line: idx + 1,
                 column: 1,
-                issue_type: IssueType::Style,
-                severity: Severity::Info,
+                rule: Rule::TabIndentation,
+                issue_type: Rule::TabIndentation.issue_type(),
+                severity: Rule::TabIndentation.default_severity(),
                 message: "Use tabs for indentation in Go, not spaces ".to_string(),
                 code: line.to_string(),
                 fix_available: true,
@@ -263,15 +267,3 @@ fn check_indentation(ast: &GoFile, path: &Path, issues: &mut Vec<Issue>) -> Resu
     
     Ok(())
 }
-
-pub fn fix_camel_case(line: &str) -> String {
-    let snake_case_regex = Regex::new(r"\b[a-z]+_[a-z][a-z0-9]*\b").unwrap();
-    snake_case_regex.replace_all(line, |caps: &regex::Captures| {
-        let first = caps.get(1).unwrap().as_str();
-        let second = caps.get(2).unwrap().as_str();
-        let mut result = String::new();
-        result.push_str(first.to_lowercase().as_str());
-        result.push_str(second.to_uppercase().as_str());
-        result
-    }).to_string()
-} 
\ No newline at end of file
@@ -0,0 +1,383 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use crate::analyzer::{Issue, Rule};
+use crate::config::Config;
+use crate::parser::GoFile;
+
+const IGNORE_DIRECTIVE: &str = "//dioxide:ignore";
+const IGNORE_FILE_DIRECTIVE: &str = "//dioxide:ignore-file";
+const NOLINT_DIRECTIVE: &str = "nolint";
+
+const STYLE_RULES: &[Rule] = &[
+    Rule::CamelCase,
+    Rule::ControlStatementSpacing,
+    Rule::BraceStyle,
+    Rule::TabIndentation,
+];
+const FORMAT_RULES: &[Rule] = &[Rule::ControlStatementSpacing, Rule::BraceStyle, Rule::TabIndentation];
+const UNUSED_RULES: &[Rule] = &[
+    Rule::UnusedFunction,
+    Rule::UnusedVariable,
+    Rule::UnusedConstant,
+    Rule::UnusedType,
+    Rule::UnusedField,
+    Rule::UnusedMethod,
+    Rule::UnreachableCode,
+];
+const TYPECHECK_RULES: &[Rule] = &[Rule::SyntaxError, Rule::UnusedImport, Rule::UnusedVariable];
+
+const DECLARATION_KINDS: &[&str] = &[
+    "function_declaration",
+    "method_declaration",
+    "type_declaration",
+    "var_declaration",
+    "const_declaration",
+    "import_declaration",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Scope {
+    File,
+    Lines(usize, usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Source {
+    Dioxide,
+    Nolint,
+}
+
+#[derive(Debug)]
+struct Directive {
+    source: Source,
+    line: usize,
+    column: usize,
+    text: String,
+    scope: Scope,
+    rules: Vec<Rule>,
+    unknown: Vec<String>,
+    matched: HashSet<Rule>,
+}
+
+impl Directive {
+    fn covers(&self, issue: &Issue) -> bool {
+        let in_scope = match self.scope {
+            Scope::File => true,
+            Scope::Lines(start, end) => issue.line >= start && issue.line <= end,
+        };
+        in_scope && (self.is_bare() || self.rules.contains(&issue.rule))
+    }
+
+    fn is_bare(&self) -> bool {
+        self.rules.is_empty() && self.unknown.is_empty()
+    }
+}
+
+pub fn apply(ast: &GoFile, path: &Path, config: &Config, mut issues: Vec<Issue>) -> Vec<Issue> {
+    let mut directives = collect_directives(ast, config);
+    if directives.is_empty() {
+        return issues;
+    }
+
+    issues.retain(|issue| {
+        let mut suppressed = false;
+        for directive in directives.iter_mut() {
+            if directive.covers(issue) {
+                directive.matched.insert(issue.rule);
+                suppressed = true;
+            }
+        }
+        !suppressed
+    });
+
+    if Rule::UnusedSuppression.is_enabled(config) {
+        for directive in directives.iter().filter(|d| d.source == Source::Dioxide) {
+            report_stale_directive(directive, path, config, &mut issues);
+        }
+    }
+
+    issues
+}
+
+fn report_stale_directive(directive: &Directive, path: &Path, config: &Config, issues: &mut Vec<Issue>) {
+    let mut messages = Vec::new();
+    if directive.is_bare() && directive.matched.is_empty() {
+        messages.push("Unused suppression directive: no finding to ignore here".to_string());
+    }
+    for rule in &directive.rules {
+        if rule.is_enabled(config) && !directive.matched.contains(rule) {
+            messages.push(format!("Unused suppression for {}: no matching finding", rule.name()));
+        }
+    }
+    for code in &directive.unknown {
+        messages.push(format!("Unknown rule '{}' in suppression directive", code));
+    }
+
+    for message in messages {
+        issues.push(Issue {
+            file_path: path.to_path_buf(),
+            line: directive.line,
+            column: directive.column,
+            rule: Rule::UnusedSuppression,
+            issue_type: Rule::UnusedSuppression.issue_type(),
+            severity: Rule::UnusedSuppression.default_severity(),
+            message,
+            code: directive.text.clone(),
+            fix_available: false,
+        });
+    }
+}
+
+fn collect_directives(ast: &GoFile, config: &Config) -> Vec<Directive> {
+    let mut directives = Vec::new();
+    for node in ast.find_nodes("comment") {
+        let text = ast.get_snippet(node.start_byte(), node.end_byte());
+        if config.general.honor_nolint {
+            if let Some(directive) = parse_nolint(ast, node, &text) {
+                directives.push(directive);
+                continue;
+            }
+        }
+        let (is_file_directive, arguments) = if let Some(rest) = text.strip_prefix(IGNORE_FILE_DIRECTIVE) {
+            (true, rest)
+        } else if let Some(rest) = text.strip_prefix(IGNORE_DIRECTIVE) {
+            (false, rest)
+        } else {
+            continue;
+        };
+        if !arguments.is_empty() && !arguments.starts_with(char::is_whitespace) {
+            continue;
+        }
+
+        let (line, column) = ast.get_position(node.start_byte());
+        let scope = if is_file_directive {
+            Scope::File
+        } else {
+            line_scope(ast, node, line)
+        };
+
+        let mut rules = Vec::new();
+        let mut unknown = Vec::new();
+        if let Some(list) = arguments.split_whitespace().next() {
+            for code in list.split(',').map(str::trim).filter(|c| !c.is_empty()) {
+                match Rule::from_code(code) {
+                    Some(rule) => rules.push(rule),
+                    None => unknown.push(code.to_string()),
+                }
+            }
+        }
+
+        directives.push(Directive {
+            source: Source::Dioxide,
+            line,
+            column,
+            text: text.trim().to_string(),
+            scope,
+            rules,
+            unknown,
+            matched: HashSet::new(),
+        });
+    }
+
+    directives
+}
+
+fn parse_nolint(ast: &GoFile, node: tree_sitter::Node, text: &str) -> Option<Directive> {
+    let body = text.strip_prefix("//")?.trim_start();
+    let arguments = body.strip_prefix(NOLINT_DIRECTIVE)?;
+    let linters = match arguments.strip_prefix(':') {
+        Some(list) => list.split(|c: char| c.is_whitespace() || c == '/').next().unwrap_or(""),
+        None if arguments.is_empty() || arguments.starts_with(char::is_whitespace) => "",
+        None => return None,
+    };
+
+    let mut rules = Vec::new();
+    let mut unknown = Vec::new();
+    for linter in linters.split(',').map(str::trim).filter(|l| !l.is_empty()) {
+        match nolint_rules(linter) {
+            Some(mapped) => rules.extend_from_slice(mapped),
+            None => unknown.push(linter.to_string()),
+        }
+    }
+    rules.sort();
+    rules.dedup();
+
+    Some(nolint_directive(ast, node, text, rules, unknown))
+}
+
+fn nolint_directive(
+    ast: &GoFile,
+    node: tree_sitter::Node,
+    text: &str,
+    rules: Vec<Rule>,
+    unknown: Vec<String>,
+) -> Directive {
+    let (line, column) = ast.get_position(node.start_byte());
+    Directive {
+        source: Source::Nolint,
+        line,
+        column,
+        text: text.trim().to_string(),
+        scope: line_scope(ast, node, line),
+        rules,
+        unknown,
+        matched: HashSet::new(),
+    }
+}
+
+fn nolint_rules(linter: &str) -> Option<&'static [Rule]> {
+    match linter.to_ascii_lowercase().as_str() {
+        "all" => Some(Rule::ALL),
+        "unused" | "deadcode" | "varcheck" | "structcheck" => Some(UNUSED_RULES),
+        "revive" | "stylecheck" | "golint" => Some(STYLE_RULES),
+        "gofmt" | "gofumpt" => Some(FORMAT_RULES),
+        "goimports" => Some(&[Rule::UnusedImport, Rule::TabIndentation]),
+        "lll" => Some(&[Rule::LineTooLong]),
+        "depguard" => Some(&[Rule::ForbiddenImport]),
+        "unparam" => Some(&[Rule::UnusedParameter]),
+        "ineffassign" => Some(&[Rule::IneffectualAssignment]),
+        "errcheck" => Some(&[Rule::UncheckedError, Rule::DiscardedError]),
+        "typecheck" => Some(TYPECHECK_RULES),
+        _ => None,
+    }
+}
+
+fn line_scope(ast: &GoFile, comment: tree_sitter::Node, line: usize) -> Scope {
+    let line_start = ast.content[..comment.start_byte()].rfind('\n').map_or(0, |i| i + 1);
+    if !ast.content[line_start..comment.start_byte()].trim().is_empty() {
+        return Scope::Lines(line, line);
+    }
+
+    let mut last_line = line;
+    let mut sibling = comment.next_named_sibling();
+    while let Some(node) = sibling {
+        let start_line = ast.get_position(node.start_byte()).0;
+        if start_line != last_line + 1 {
+            break;
+        }
+        if node.kind() != "comment" {
+            if DECLARATION_KINDS.contains(&node.kind()) {
+                return Scope::Lines(start_line, ast.get_position(node.end_byte()).0);
+            }
+            break;
+        }
+        last_line = start_line;
+        sibling = node.next_named_sibling();
+    }
+
+    Scope::Lines(last_line + 1, last_line + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{issue, parse};
+
+    fn remaining(source: &str, issues: &[(Rule, usize)]) -> Vec<(Rule, usize, String)> {
+        let ast = parse(source);
+        let path = Path::new("p.go");
+        let issues = issues.iter().map(|&(rule, line)| issue(path, rule, line, 1, "")).collect();
+        apply(&ast, path, &Config::default(), issues)
+            .into_iter()
+            .map(|issue| (issue.rule, issue.line, issue.message))
+            .collect()
+    }
+
+    fn rules(remaining: &[(Rule, usize, String)]) -> Vec<(Rule, usize)> {
+        remaining.iter().map(|(rule, line, _)| (*rule, *line)).collect()
+    }
+
+    #[test]
+    fn same_line_directive_covers_only_its_line_and_rule() {
+        let source = "package p\n\nvar a = 1 //dioxide:ignore DX1003\nvar b = 2\n";
+        let left = remaining(source, &[(Rule::UnusedVariable, 3), (Rule::LineTooLong, 3), (Rule::UnusedVariable, 4)]);
+        assert_eq!(rules(&left), [(Rule::LineTooLong, 3), (Rule::UnusedVariable, 4)]);
+    }
+
+    #[test]
+    fn preceding_line_directive_covers_the_next_line() {
+        let source = "package p\n\nfunc f() {\n\t//dioxide:ignore unused-variable,DX2002\n\tx := 1\n\ty := 2\n}\n";
+        let left = remaining(
+            source,
+            &[(Rule::UnusedVariable, 5), (Rule::LineTooLong, 5), (Rule::UnusedVariable, 6)],
+        );
+        assert_eq!(rules(&left), [(Rule::UnusedVariable, 6)]);
+    }
+
+    #[test]
+    fn file_directive_covers_every_line() {
+        let source = "//dioxide:ignore-file DX3004\npackage p\n\nfunc f() {\n    return\n}\n";
+        let left = remaining(source, &[(Rule::TabIndentation, 5), (Rule::UnusedFunction, 4)]);
+        assert_eq!(rules(&left), [(Rule::UnusedFunction, 4)]);
+    }
+
+    #[test]
+    fn directive_before_a_function_covers_its_block() {
+        let source = "package p\n\n//dioxide:ignore DX3004\n// f is indented with spaces.\nfunc f() {\n    return\n}\n\nfunc g() {\n    return\n}\n";
+        let left = remaining(source, &[(Rule::TabIndentation, 6), (Rule::TabIndentation, 10)]);
+        assert_eq!(rules(&left), [(Rule::TabIndentation, 10)]);
+    }
+
+    #[test]
+    fn reports_stale_and_unknown_directives() {
+        let source = "package p\n\nvar a = 1 //dioxide:ignore DX1003,DX2002\nvar b = 2 //dioxide:ignore\nvar c = 3 //dioxide:ignore DX9999\nvar d = 4 //dioxide:ignored DX1003\n";
+        let left = remaining(source, &[(Rule::UnusedVariable, 3), (Rule::UnusedVariable, 6)]);
+        let reported: Vec<(Rule, usize, &str)> =
+            left.iter().map(|(rule, line, message)| (*rule, *line, message.as_str())).collect();
+        assert_eq!(
+            reported,
+            [
+                (Rule::UnusedVariable, 6, ""),
+                (Rule::UnusedSuppression, 3, "Unused suppression for line-too-long: no matching finding"),
+                (Rule::UnusedSuppression, 4, "Unused suppression directive: no finding to ignore here"),
+                (Rule::UnusedSuppression, 5, "Unknown rule 'DX9999' in suppression directive"),
+            ]
+        );
+    }
+
+    fn directives(source: &str, honor_nolint: bool) -> Vec<Directive> {
+        let mut config = Config::default();
+        config.general.honor_nolint = honor_nolint;
+        collect_directives(&parse(source), &config)
+    }
+
+    #[test]
+    fn parses_nolint_linter_lists() {
+        let source = "package p\n\nvar a = 1 //nolint:unused,errcheck // legacy\nvar b = 2 // nolint:LLL\nvar c = 3 //nolint\nvar d = 4 //nolint:made-up\nvar e = 5 //nolintx\n";
+        let parsed = directives(source, true);
+        assert_eq!(parsed.len(), 4);
+
+        assert_eq!(parsed[0].scope, Scope::Lines(3, 3));
+        assert!(parsed[0].rules.contains(&Rule::UnusedVariable));
+        assert!(!parsed[0].rules.contains(&Rule::LineTooLong));
+        assert_eq!(parsed[1].rules, [Rule::LineTooLong]);
+        assert!(parsed[2].is_bare());
+        assert_eq!(parsed[3].unknown, ["made-up"]);
+        assert!(!parsed[3].is_bare());
+    }
+
+    #[test]
+    fn nolint_requires_the_config_switch() {
+        let source = "package p\n\nvar a = 1 //nolint:unused\nvar b = 2 //dioxide:ignore DX1002\n";
+        let parsed = directives(source, false);
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].source, Source::Dioxide);
+    }
+
+    #[test]
+    fn leading_nolint_covers_the_next_declaration() {
+        let source = "package p\n\n//nolint:unused\n// helper is kept for tests.\nfunc helper() {\n\tprintln()\n}\n";
+        let parsed = directives(source, true);
+        assert_eq!(parsed[0].scope, Scope::Lines(5, 7));
+    }
+
+    #[test]
+    fn stale_nolint_directives_are_not_reported() {
+        let source = "package p\n\nvar a = 1 //nolint:lll\n";
+        let ast = parse(source);
+        let mut config = Config::default();
+        config.general.honor_nolint = true;
+        assert!(apply(&ast, Path::new("p.go"), &config, Vec::new()).is_empty());
+    }
+}
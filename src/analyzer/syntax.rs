@@ -1,7 +1,7 @@
 use anyhow::Result;
 use std::path::Path;
 
-use crate::analyzer::{Issue, IssueType, Severity};
+use crate::analyzer::{Issue, Rule};
 use crate::config::Config;
 use crate::parser::GoFile;
 
@@ -25,11 +25,11 @@ fn check_syntax_errors(ast: &GoFile, path: &Path, issues: &mut Vec<Issue>) -> Re
             let parent_type = parent_node.kind();
             
             match parent_type {
-                "function_declaration " => "Syntax error in function declaration ".to_string(),
-                "import_declaration " => "Syntax error in import statement ".to_string(),
-                "var_declaration " => "Syntax error in variable declaration ".to_string(),
-                "if_statement " => "Syntax error in if statement ".to_string(),
-                "for_statement " => "Syntax error in for loop ".to_string(),
+                "function_declaration" => "Syntax error in function declaration ".to_string(),
+                "import_declaration" => "Syntax error in import statement ".to_string(),
+                "var_declaration" => "Syntax error in variable declaration ".to_string(),
+                "if_statement" => "Syntax error in if statement ".to_string(),
+                "for_statement" => "Syntax error in for loop ".to_string(),
                 _ => format!("Syntax error in {}", parent_type),
             }
         } else {
@@ -39,8 +39,9 @@ fn check_syntax_errors(ast: &GoFile, path: &Path, issues: &mut Vec<Issue>) -> Re
             file_path: path.to_path_buf(),
             line,
             column,
-            issue_type: IssueType::Syntax,
-            severity: Severity::Error,
+            rule: Rule::SyntaxError,
+            issue_type: Rule::SyntaxError.issue_type(),
+            severity: Rule::SyntaxError.default_severity(),
             message,
             code: snippet,
             fix_available: is_fixable_syntax_error(&node, ast),
@@ -65,8 +66,9 @@ fn check_line_length(ast: &GoFile, path: &Path, config: &Config, issues: &mut Ve
                 file_path: path.to_path_buf(),
                 line: line_num,
                 column: 1,
-                issue_type: IssueType::Syntax,
-                severity: Severity::Warning,
+                rule: Rule::LineTooLong,
+                issue_type: Rule::LineTooLong.issue_type(),
+                severity: Rule::LineTooLong.default_severity(),
                 message: format!("Line too long ({} > {} characters)", line.len(), max_line_length),
                 code: line.to_string(),
                 fix_available: is_fixable_line_length(line),
@@ -85,9 +87,9 @@ fn is_fixable_syntax_error(node: &tree_sitter::Node, ast: &GoFile) -> bool {
     
     if let Some(parent_node) = parent {
         match parent_node.kind() {
-            "import_declaration " => true,
-            "function_declaration " => node_text.contains("func ") && !node_text.contains("{"),
-            "block " => !node_text.contains("}"),
+            "import_declaration" => true,
+            "function_declaration" => node_text.contains("func ") && !node_text.contains("{"),
+            "block" => !node_text.contains("}"),
             _ => false,
         }
     } else {
@@ -0,0 +1,237 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use crate::analyzer::Issue;
+use crate::parser::{self, GoFile};
+
+const BASELINE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Fingerprint {
+    pub rule: String,
+    pub file: String,
+    pub function: String,
+    pub code: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Entry {
+    #[serde(flatten)]
+    fingerprint: Fingerprint,
+    count: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Baseline {
+    version: u32,
+    entries: Vec<Entry>,
+}
+
+impl Baseline {
+    pub fn from_issues(issues: &[Issue]) -> Self {
+        let mut fingerprinter = Fingerprinter::default();
+        let mut counts = BTreeMap::new();
+        for issue in issues {
+            *counts.entry(fingerprinter.fingerprint(issue)).or_insert(0) += 1;
+        }
+
+        Baseline {
+            version: BASELINE_VERSION,
+            entries: counts
+                .into_iter()
+                .map(|(fingerprint, count)| Entry { fingerprint, count })
+                .collect(),
+        }
+    }
+
+    pub fn filter_new(&self, issues: Vec<Issue>) -> (Vec<Issue>, usize) {
+        let mut remaining: HashMap<&Fingerprint, usize> = self
+            .entries
+            .iter()
+            .map(|entry| (&entry.fingerprint, entry.count))
+            .collect();
+        let mut fingerprinter = Fingerprinter::default();
+        let mut known = 0;
+        let new_issues = issues
+            .into_iter()
+            .filter(|issue| {
+                let fingerprint = fingerprinter.fingerprint(issue);
+                match remaining.get_mut(&fingerprint) {
+                    Some(count) if *count > 0 => {
+                        *count -= 1;
+                        known += 1;
+                        false
+                    }
+                    _ => true,
+                }
+            })
+            .collect();
+
+        (new_issues, known)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.iter().map(|entry| entry.count).sum()
+    }
+}
+
+#[derive(Default)]
+struct Fingerprinter {
+    files: HashMap<PathBuf, Option<GoFile>>,
+}
+
+impl Fingerprinter {
+    fn fingerprint(&mut self, issue: &Issue) -> Fingerprint {
+        let ast = self
+            .files
+            .entry(issue.file_path.clone())
+            .or_insert_with(|| parser::parse_file(&issue.file_path).ok());
+
+        Fingerprint {
+            rule: issue.rule.id().to_string(),
+            file: normalize_path(&issue.file_path),
+            function: ast
+                .as_ref()
+                .map(|ast| enclosing_function(ast, issue.line))
+                .unwrap_or_default(),
+            code: issue.code.split_whitespace().collect::<Vec<_>>().join(" "),
+        }
+    }
+}
+
+fn enclosing_function(ast: &GoFile, line: usize) -> String {
+    let mut declarations = ast.find_nodes("function_declaration");
+    declarations.extend(ast.find_nodes("method_declaration"));
+
+    for node in declarations {
+        let start_line = node.start_position().row + 1;
+        let end_line = node.end_position().row + 1;
+        if line < start_line || line > end_line {
+            continue;
+        }
+
+        let name = node
+            .child_by_field_name("name")
+            .map(|n| ast.get_snippet(n.start_byte(), n.end_byte()))
+            .unwrap_or_default();
+        return match node.child_by_field_name("receiver") {
+            Some(receiver) => format!("{}.{}", receiver_type(ast, receiver), name),
+            None => name,
+        };
+    }
+
+    String::new()
+}
+
+fn receiver_type(ast: &GoFile, receiver: tree_sitter::Node) -> String {
+    let mut cursor = receiver.walk();
+    let declaration = receiver.named_children(&mut cursor).next();
+    declaration
+        .and_then(|d| d.child_by_field_name("type"))
+        .map(|t| ast.get_snippet(t.start_byte(), t.end_byte()))
+        .unwrap_or_default()
+}
+
+fn normalize_path(path: &Path) -> String {
+    let relative = std::env::current_dir()
+        .ok()
+        .and_then(|cwd| path.strip_prefix(cwd).ok().map(Path::to_path_buf))
+        .unwrap_or_else(|| path.to_path_buf());
+
+    relative
+        .components()
+        .filter(|c| !matches!(c, Component::CurDir))
+        .map(|c| c.as_os_str().to_string_lossy().to_string())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+pub fn load(path: &Path) -> Result<Baseline> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read baseline file: {}", path.display()))?;
+    let baseline: Baseline = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse baseline file: {}", path.display()))?;
+    if baseline.version != BASELINE_VERSION {
+        return Err(anyhow::anyhow!(
+            "Unsupported baseline version {} in {} (expected {})",
+            baseline.version,
+            path.display(),
+            BASELINE_VERSION
+        ));
+    }
+
+    Ok(baseline)
+}
+
+pub fn write(path: &Path, baseline: &Baseline) -> Result<()> {
+    let content = serde_json::to_string_pretty(baseline).context("Failed to serialize baseline")?;
+    fs::write(path, content + "\n")
+        .with_context(|| format!("Failed to write baseline file: {}", path.display()))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::Rule;
+    use crate::test_support::{self, TempDir};
+
+    fn issue(file_path: &Path, line: usize, code: &str) -> Issue {
+        test_support::issue(file_path, Rule::UnusedVariable, line, 2, code)
+    }
+
+    #[test]
+    fn fingerprints_survive_line_shifts() {
+        let dir = TempDir::new("baseline");
+        let path = dir.write("shift.go", "package p\n\ntype T struct{}\n\nfunc (t *T) m() {\n\tx := 1\n}\n");
+        let baseline = Baseline::from_issues(&[issue(&path, 6, "x  :=  1")]);
+        assert_eq!(baseline.entries[0].fingerprint.function, "*T.m");
+        assert_eq!(baseline.entries[0].fingerprint.code, "x := 1");
+
+        dir.write("shift.go", "package p\n\n// T does things.\ntype T struct{}\n\nfunc (t *T) m() {\n\tx := 1\n}\n");
+        let (new_issues, known) = baseline.filter_new(vec![issue(&path, 7, "x := 1")]);
+        assert!(new_issues.is_empty());
+        assert_eq!(known, 1);
+    }
+
+    #[test]
+    fn reports_issues_beyond_the_baselined_count() {
+        let dir = TempDir::new("baseline");
+        let path = dir.write("count.go", "package p\n\nfunc f() {\n\tx := 1\n\tx := 1\n}\n\nfunc g() {\n\tx := 1\n}\n");
+        let baseline = Baseline::from_issues(&[issue(&path, 4, "x := 1")]);
+        let (new_issues, known) = baseline.filter_new(vec![
+            issue(&path, 4, "x := 1"),
+            issue(&path, 5, "x := 1"),
+            issue(&path, 9, "x := 1"),
+        ]);
+
+        let lines: Vec<usize> = new_issues.iter().map(|issue| issue.line).collect();
+        assert_eq!(lines, [5, 9]);
+        assert_eq!(known, 1);
+        assert_eq!(baseline.len(), 1);
+    }
+
+    #[test]
+    fn normalizes_paths() {
+        assert_eq!(normalize_path(Path::new("./pkg/a.go")), "pkg/a.go");
+        let cwd = std::env::current_dir().unwrap();
+        assert_eq!(normalize_path(&cwd.join("pkg").join("a.go")), "pkg/a.go");
+    }
+
+    #[test]
+    fn round_trips_and_checks_version() {
+        let dir = TempDir::new("baseline");
+        let path = dir.write("baseline.json", "");
+        let baseline = Baseline::from_issues(&[issue(Path::new("missing.go"), 1, "x")]);
+        write(&path, &baseline).unwrap();
+        assert_eq!(load(&path).unwrap().len(), 1);
+
+        dir.write("baseline.json", "{\"version\": 2, \"entries\": []}");
+        let error = load(&path).unwrap_err();
+        assert!(error.to_string().contains("Unsupported baseline version 2"));
+    }
+}
@@ -1,8 +1,11 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use crate::analyzer::Severity;
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Config {
     pub general: GeneralConfig,
@@ -13,6 +16,10 @@ pub struct Config {
 pub struct GeneralConfig {
     pub ignore_patterns: Vec<String>,
     pub exclude_dirs: Vec<String>,
+    #[serde(default)]
+    pub honor_nolint: bool,
+    #[serde(default = "default_fail_on")]
+    pub fail_on: Severity,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -21,6 +28,8 @@ pub struct Rules {
     pub dead_code: DeadCodeRules,
     pub style: StyleRules,
     pub architecture: ArchitectureRules,
+    #[serde(default)]
+    pub errors: ErrorRules,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -35,6 +44,26 @@ pub struct DeadCodeRules {
     pub detect_unused_imports: bool,
     pub detect_unused_functions: bool,
     pub detect_unused_variables: bool,
+    #[serde(default = "default_true")]
+    pub detect_unused_suppressions: bool,
+    #[serde(default)]
+    pub detect_unused_exported: bool,
+    #[serde(default = "default_true")]
+    pub detect_unused_parameters: bool,
+    #[serde(default = "default_true")]
+    pub detect_unused_constants: bool,
+    #[serde(default = "default_true")]
+    pub detect_unused_types: bool,
+    #[serde(default = "default_true")]
+    pub detect_unused_fields: bool,
+    #[serde(default = "default_true")]
+    pub detect_unused_methods: bool,
+    #[serde(default = "default_true")]
+    pub detect_unreachable_code: bool,
+    #[serde(default = "default_true")]
+    pub detect_ineffectual_assignments: bool,
+    #[serde(default)]
+    pub api_roots: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -50,6 +79,73 @@ pub struct ArchitectureRules {
     pub enabled: bool,
     pub enforce_package_boundaries: bool,
     pub detect_circular_dependencies: bool,
+    #[serde(default)]
+    pub layers: BTreeMap<String, GlobList>,
+    #[serde(default)]
+    pub layer_rules: BTreeMap<String, LayerRule>,
+    #[serde(default)]
+    pub max_instability: Option<f64>,
+    #[serde(default)]
+    pub min_dependents: Option<usize>,
+    #[serde(default)]
+    pub forbidden_imports: Vec<ForbiddenImport>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ErrorRules {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    #[serde(default = "default_true")]
+    pub detect_unchecked_errors: bool,
+    #[serde(default = "default_true")]
+    pub detect_discarded_errors: bool,
+    #[serde(default = "default_error_excludes")]
+    pub exclude: Vec<String>,
+}
+
+impl Default for ErrorRules {
+    fn default() -> Self {
+        ErrorRules {
+            enabled: true,
+            detect_unchecked_errors: true,
+            detect_discarded_errors: true,
+            exclude: default_error_excludes(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum GlobList {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl GlobList {
+    pub fn patterns(&self) -> &[String] {
+        match self {
+            GlobList::One(pattern) => std::slice::from_ref(pattern),
+            GlobList::Many(patterns) => patterns,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LayerRule {
+    #[serde(default)]
+    pub allow: Option<Vec<String>>,
+    #[serde(default)]
+    pub deny: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ForbiddenImport {
+    pub path: String,
+    pub reason: String,
+    #[serde(default)]
+    pub replacement: Option<String>,
+    #[serde(default)]
+    pub scope: Vec<String>,
 }
 
 impl Default for Config {
@@ -67,6 +163,8 @@ impl Default for Config {
                     "build".to_string(),
                     "dist".to_string(),
                 ],
+                honor_nolint: false,
+                fail_on: default_fail_on(),
             },
             rules: Rules {
                 syntax: SyntaxRules {
@@ -78,6 +176,16 @@ impl Default for Config {
                     detect_unused_imports: true,
                     detect_unused_functions: true,
                     detect_unused_variables: true,
+                    detect_unused_suppressions: true,
+                    detect_unused_exported: false,
+                    detect_unused_parameters: true,
+                    detect_unused_constants: true,
+                    detect_unused_types: true,
+                    detect_unused_fields: true,
+                    detect_unused_methods: true,
+                    detect_unreachable_code: true,
+                    detect_ineffectual_assignments: true,
+                    api_roots: Vec::new(),
                 },
                 style: StyleRules {
                     enabled: true,
@@ -89,12 +197,40 @@ impl Default for Config {
                     enabled: true,
                     enforce_package_boundaries: true,
                     detect_circular_dependencies: true,
+                    layers: BTreeMap::new(),
+                    layer_rules: BTreeMap::new(),
+                    max_instability: None,
+                    min_dependents: None,
+                    forbidden_imports: Vec::new(),
                 },
+                errors: ErrorRules::default(),
             },
         }
     }
 }
 
+fn default_true() -> bool {
+    true
+}
+
+fn default_error_excludes() -> Vec<String> {
+    [
+        "fmt.Print*",
+        "(*bytes.Buffer).Write*",
+        "(*strings.Builder).Write*",
+        "(hash.Hash).Write",
+        "math/rand.Read",
+        "(*math/rand.Rand).Read",
+    ]
+    .iter()
+    .map(|name| name.to_string())
+    .collect()
+}
+
+fn default_fail_on() -> Severity {
+    Severity::Error
+}
+
 pub fn find_default_config() -> PathBuf {
     let cwd = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
     let config_paths = [
@@ -2,9 +2,11 @@ use anyhow::Result;
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
+use tree_sitter::Node;
 
-use crate::analyzer::Issue;
+use crate::analyzer::{Issue, Rule};
 use crate::config::Config;
+use crate::parser;
 
 pub fn apply_fixes(_path: &Path, issues: &[Issue], config: &Config) -> Result<usize> {
     let mut fixed_count = 0;
@@ -26,33 +28,30 @@ pub fn apply_fixes(_path: &Path, issues: &[Issue], config: &Config) -> Result<us
             modified_files.insert(file_path.clone(), content);
         }
     }
-    for issue in issues {
-        if !issue.fix_available {
-            continue;
-        }
-        
+    let mut fixable: Vec<&Issue> = issues.iter().filter(|issue| issue.fix_available).collect();
+    fixable.sort_by(|a, b| {
+        a.file_path
+            .cmp(&b.file_path)
+            .then(b.line.cmp(&a.line))
+            .then(b.column.cmp(&a.column))
+    });
+    for issue in fixable {
         if let Some(file_content) = modified_files.get_mut(&issue.file_path) {
-            println!("Attempting to fix: {} in {}", issue.message, issue.file_path.display());
-            
-            let fixed = match issue.issue_type {
-                crate::analyzer::IssueType::Syntax => fix_syntax_issue(issue, file_content, config),
-                crate::analyzer::IssueType::DeadCode => fix_dead_code_issue(issue, file_content, config),
-                crate::analyzer::IssueType::Style => fix_style_issue(issue, file_content, config),
-                crate::analyzer::IssueType::Architecture => false,
-            };
+            eprintln!("Attempting to fix: {} in {}", issue.message, issue.file_path.display());
             
-            if fixed {
-                println!("  ✓ Successfully fixed issue ");
+            let before = file_content.clone();
+            if fix_issue(issue, file_content, config) && *file_content != before {
+                eprintln!("  ✓ Successfully fixed issue ");
                 fixed_count += 1;
             } else {
-                println!("  ✗ Could not fix issue automatically ");
+                eprintln!("  ✗ Could not fix issue automatically ");
             }
         }
     }
     for (file_path, content) in modified_files {
-        println!("Writing changes to file: {}", file_path.display());
+        eprintln!("Writing changes to file: {}", file_path.display());
         match fs::write(&file_path, content) {
-            Ok(_) => println!("  ✓ Successfully wrote changes "),
+            Ok(_) => eprintln!("  ✓ Successfully wrote changes "),
             Err(e) => {
                 eprintln!("Failed to write fixes to file {}: {}", file_path.display(), e);
                 let issue_count_in_file = issues.iter()
@@ -68,6 +67,54 @@ pub fn apply_fixes(_path: &Path, issues: &[Issue], config: &Config) -> Result<us
     Ok(fixed_count)
 }
 
+pub fn preview_fix(issue: &Issue, config: &Config) -> Option<(String, String)> {
+    if !issue.fix_available {
+        return None;
+    }
+    
+    let original = fs::read_to_string(&issue.file_path).ok()?;
+    let mut fixed = original.clone();
+    if fix_issue(issue, &mut fixed, config) && fixed != original {
+        Some((original, fixed))
+    } else {
+        None
+    }
+}
+
+fn fix_issue(issue: &Issue, content: &mut String, config: &Config) -> bool {
+    let had_trailing_newline = content.ends_with('\n');
+    let fixed = match issue.rule {
+        Rule::SyntaxError => fix_syntax_issue(issue, content, config),
+        Rule::UnusedImport | Rule::UnusedFunction | Rule::UnusedVariable | Rule::UnusedParameter => {
+            fix_dead_code_issue(issue, content, config)
+        }
+        Rule::LineTooLong | Rule::CamelCase | Rule::ControlStatementSpacing | Rule::TabIndentation => {
+            fix_style_issue(issue, content, config)
+        }
+        Rule::UnusedSuppression
+        | Rule::UnusedExported
+        | Rule::UnusedConstant
+        | Rule::UnusedType
+        | Rule::UnusedField
+        | Rule::UnusedMethod
+        | Rule::UnreachableCode
+        | Rule::IneffectualAssignment
+        | Rule::BraceStyle
+        | Rule::PackageBoundary
+        | Rule::InternalImport
+        | Rule::CircularDependency
+        | Rule::UnstableDependency
+        | Rule::ForbiddenImport
+        | Rule::UncheckedError
+        | Rule::DiscardedError => false,
+    };
+    if fixed && had_trailing_newline && !content.ends_with('\n') {
+        content.push('\n');
+    }
+    
+    fixed
+}
+
 fn fix_syntax_issue(issue: &Issue, content: &mut String, _config: &Config) -> bool {
     let lines: Vec<&str> = content.lines().collect();
     
@@ -103,13 +150,13 @@ fn fix_syntax_issue(issue: &Issue, content: &mut String, _config: &Config) -> bo
     if fixed {
         let mut result = String::new();
         if line_idx > 0 {
-            result.push_str(&lines[..line_idx].join("\n "));
-            result.push_str("\n ");
+            result.push_str(&lines[..line_idx].join("\n"));
+            result.push('\n');
         }
         result.push_str(&fixed_line);
         if line_idx < lines.len() - 1 {
-            result.push_str("\n ");
-            result.push_str(&lines[(line_idx + 1)..].join("\n "));
+            result.push('\n');
+            result.push_str(&lines[(line_idx + 1)..].join("\n"));
         }
         
         *content = result;
@@ -124,21 +171,21 @@ fn fix_dead_code_issue(issue: &Issue, content: &mut String, _config: &Config) ->
     if issue.line > lines.len() {
         return false;
     }
-    if issue.message.contains("unused import ") {
-        println!("  Fixing unused import: {}", issue.code);
+    if issue.rule == Rule::UnusedImport {
+        log::debug!("Fixing unused import: {}", issue.code);
         let import_text = issue.code.trim_matches('"');
-        println!("  Import text to remove: \"{}\"", import_text);
+        log::debug!("Import text to remove: \"{}\"", import_text);
         let block_import_regex = regex::Regex::new(r"import\s*\(\s*((?:.|\n)*?)\s*\)").unwrap();
         let _single_import_regex = regex::Regex::new(r#"import\s+"([^"]+)""#).unwrap();
-        println!("  Checking for block imports...");
+        log::debug!("Checking for block imports...");
         if let Some(caps) = block_import_regex.captures(content) {
-            println!("  Found block imports ");
+            log::debug!("Found block imports ");
             let imports_block = caps.get(1).unwrap().as_str();
             let mut import_lines: Vec<&str> = imports_block.lines().collect();
             
-            println!("  Original import block:");
+            log::debug!("Original import block:");
             for line in &import_lines {
-                println!("    \"{}\"", line);
+                log::debug!("  \"{}\"", line);
             }
             let before_count = import_lines.len();
             import_lines.retain(|line| {
@@ -146,42 +193,42 @@ fn fix_dead_code_issue(issue: &Issue, content: &mut String, _config: &Config) ->
                 let contains_import = trimmed.contains(import_text) || trimmed == format!("\"{}\"", import_text);
                 let keep = !contains_import || trimmed.starts_with("//");
                 if !keep {
-                    println!("  Removing line: \"{}\"", line);
+                    log::debug!("Removing line: \"{}\"", line);
                 }
                 keep
             });
             
-            println!("  Import lines after filtering: {}", import_lines.len());
+            log::debug!("Import lines after filtering: {}", import_lines.len());
             if import_lines.len() < before_count {
                 let new_imports = import_lines.join("\n");
                 let replacement = if new_imports.trim().is_empty() {
-                    println!("  No imports left, removing entire block ");
+                    log::debug!("No imports left, removing entire block ");
                     String::from("")
                 } else {
-                    println!("  Creating new import block ");
+                    log::debug!("Creating new import block ");
                     format!("import (\n{}\n)", new_imports)
                 };
                 
                 *content = block_import_regex.replace(content, replacement).to_string();
                 return true;
             } else {
-                println!("  No imports removed from block ");
+                log::debug!("No imports removed from block ");
             }
         } else {
-            println!("  No block imports found ");
+            log::debug!("No block imports found ");
         }
-        println!("  Checking for single line imports...");
+        log::debug!("Checking for single line imports...");
         let import_with_package = format!("import \"{}\"", import_text);
-        println!("  Looking for: \"{}\"", import_with_package);
+        log::debug!("Looking for: \"{}\"", import_with_package);
         
         if content.contains(&import_with_package) {
-            println!("  Found single line import to remove ");
+            log::debug!("Found single line import to remove ");
             let mut new_content = String::new();
             let mut removed = false;
             
             for line in content.lines() {
                 if line.trim() == import_with_package {
-                    println!("  Removing line: \"{}\"", line);
+                    log::debug!("Removing line: \"{}\"", line);
                     removed = true;
                     continue;
                 }
@@ -193,56 +240,203 @@ fn fix_dead_code_issue(issue: &Issue, content: &mut String, _config: &Config) ->
                 *content = new_content;
                 return true;
             } else {
-                println!("  Couldn't remove single line import ");
+                log::debug!("Couldn't remove single line import ");
             }
         } else {
-            println!("  No matching single line import found ");
+            log::debug!("No matching single line import found ");
         }
-        println!("  Attempting line-by-line search for import...");
+        log::debug!("Attempting line-by-line search for import...");
         let original_line = lines[issue.line - 1].trim();
-        println!("  Original line ({}): \"{}\"", issue.line, original_line);
+        log::debug!("Original line ({}): \"{}\"", issue.line, original_line);
         
         if original_line.contains(import_text) {
-            println!("  Found import in line, removing...");
+            log::debug!("Found import in line, removing...");
             let mut result = String::new();
             if issue.line > 1 {
-                result.push_str(&lines[..issue.line-1].join("\n "));
-                result.push_str("\n ");
+                result.push_str(&lines[..issue.line-1].join("\n"));
+                result.push('\n');
             }
             if issue.line < lines.len() {
-                result.push_str(&lines[issue.line..].join("\n "));
+                result.push_str(&lines[issue.line..].join("\n"));
             }
             
             *content = result;
             return true;
         }
         
-        println!("  Could not find and remove the import ");
+        log::debug!("Could not find and remove the import ");
         return false;
-    } else if issue.message.contains("unused variable ") || issue.message.contains("unused function ") {
+    } else if issue.rule == Rule::UnusedParameter {
         let line_idx = issue.line - 1;
-        if line_idx < lines.len() {
-            let mut result = String::new();
-            if line_idx > 0 {
-                result.push_str(&lines[..line_idx].join("\n "));
-                result.push_str("\n ");
-            }
-            result.push_str("// Commented out unused code\n");
-            result.push_str(lines[line_idx]);
-            result.push_str("\n// End of commented code\n");
-            if line_idx < lines.len() - 1 {
-                result.push_str("\n ");
-                result.push_str(&lines[(line_idx + 1)..].join("\n "));
-            }
-            
-            *content = result;
-            return true;
+        let line = lines[line_idx];
+        let Some(start) = line.char_indices().nth(issue.column.saturating_sub(1)).map(|(i, _)| i) else {
+            return false;
+        };
+        let end = start + issue.code.len();
+        let is_identifier = |c: char| c.is_alphanumeric() || c == '_';
+        if !line[start..].starts_with(issue.code.as_str())
+            || line[..start].ends_with(is_identifier)
+            || line[end..].starts_with(is_identifier)
+        {
+            log::debug!("Parameter {} is no longer at {}:{}", issue.code, issue.line, issue.column);
+            return false;
+        }
+        
+        let fixed_line = format!("{}_{}", &line[..start], &line[end..]);
+        let mut result = String::new();
+        if line_idx > 0 {
+            result.push_str(&lines[..line_idx].join("\n"));
+            result.push('\n');
+        }
+        result.push_str(&fixed_line);
+        if line_idx < lines.len() - 1 {
+            result.push('\n');
+            result.push_str(&lines[(line_idx + 1)..].join("\n"));
         }
+        
+        *content = result;
+        return true;
+    } else if issue.rule == Rule::UnusedFunction {
+        let name: String = issue
+            .code
+            .strip_prefix("func ")
+            .unwrap_or(&issue.code)
+            .chars()
+            .take_while(|c| c.is_alphanumeric() || *c == '_')
+            .collect();
+        return remove_declaration(issue, content, "function_declaration", &name);
+    } else if issue.rule == Rule::UnusedVariable {
+        return remove_variable(issue, content);
     }
     
     false
 }
 
+fn remove_declaration(issue: &Issue, content: &mut String, kind: &str, name: &str) -> bool {
+    let Some(offset) = byte_offset(content, issue.line, issue.column) else {
+        return false;
+    };
+    let Some(tree) = parser::init_parser().ok().and_then(|mut parser| parser.parse(content.as_str(), None)) else {
+        return false;
+    };
+    let mut node = tree.root_node().descendant_for_byte_range(offset, offset);
+    while let Some(current) = node {
+        if current.kind() == kind {
+            break;
+        }
+        node = current.parent();
+    }
+    let declaration = node.filter(|node| {
+        node.start_byte() == offset
+            && !node.has_error()
+            && node
+                .child_by_field_name("name")
+                .is_some_and(|name_node| &content[name_node.start_byte()..name_node.end_byte()] == name)
+    });
+    let Some(declaration) = declaration else {
+        log::debug!("No {} {} starts at {}:{}", kind, name, issue.line, issue.column);
+        return false;
+    };
+    
+    remove_lines(content, declaration.start_byte(), declaration.end_byte())
+}
+
+fn remove_variable(issue: &Issue, content: &mut String) -> bool {
+    let Some(offset) = byte_offset(content, issue.line, issue.column) else {
+        return false;
+    };
+    let Some(tree) = parser::init_parser().ok().and_then(|mut parser| parser.parse(content.as_str(), None)) else {
+        return false;
+    };
+    let identifier = tree
+        .root_node()
+        .descendant_for_byte_range(offset, offset)
+        .filter(|node| node.kind() == "identifier" && node.start_byte() == offset)
+        .filter(|node| content[node.start_byte()..node.end_byte()] == issue.code);
+    let Some(declaration) = identifier.and_then(|identifier| removable_variable(identifier, content)) else {
+        log::debug!("Variable {} at {}:{} cannot be removed safely", issue.code, issue.line, issue.column);
+        return false;
+    };
+    
+    remove_lines(content, declaration.start_byte(), declaration.end_byte())
+}
+
+pub fn removable_variable<'t>(identifier: Node<'t>, content: &str) -> Option<Node<'t>> {
+    let parent = identifier.parent()?;
+    let declaration = parent.parent()?;
+    let value = match (parent.kind(), declaration.kind()) {
+        ("var_spec", "var_declaration") if declaration.named_child_count() == 1 => {
+            let mut cursor = parent.walk();
+            if parent.children_by_field_name("name", &mut cursor).count() != 1 {
+                return None;
+            }
+            parent.child_by_field_name("value")
+        }
+        ("expression_list", "short_var_declaration")
+            if declaration.child_by_field_name("left") == Some(parent) && parent.named_child_count() == 1 =>
+        {
+            declaration.child_by_field_name("right")
+        }
+        _ => return None,
+    };
+    if declaration.parent()?.kind() != "block" || value.is_some_and(|value| has_side_effects(value, content)) {
+        return None;
+    }
+    
+    Some(declaration)
+}
+
+fn has_side_effects(node: Node, content: &str) -> bool {
+    if node.kind() == "call_expression"
+        || (node.kind() == "unary_expression"
+            && node
+                .child_by_field_name("operator")
+                .is_some_and(|operator| &content[operator.start_byte()..operator.end_byte()] == "<-"))
+    {
+        return true;
+    }
+    parser::named_children(node).into_iter().any(|child| has_side_effects(child, content))
+}
+
+fn remove_lines(content: &mut String, start_byte: usize, end_byte: usize) -> bool {
+    let mut start = content[..start_byte].rfind('\n').map_or(0, |i| i + 1);
+    let mut end = content[end_byte..].find('\n').map_or(content.len(), |i| end_byte + i + 1);
+    if !content[start..start_byte].trim().is_empty() || !content[end_byte..end].trim().is_empty() {
+        log::debug!("Declaration shares its lines with other code");
+        return false;
+    }
+    while start > 0 {
+        let previous = content[..start - 1].rfind('\n').map_or(0, |i| i + 1);
+        if !content[previous..start].trim_start().starts_with("//") {
+            break;
+        }
+        start = previous;
+    }
+    
+    let blank_before = start == 0 || content[..start].ends_with("\n\n");
+    if blank_before && content[end..].starts_with('\n') {
+        end += 1;
+    } else if blank_before && end == content.len() && start > 0 {
+        start -= 1;
+    }
+    content.replace_range(start..end, "");
+    true
+}
+
+fn byte_offset(content: &str, line: usize, column: usize) -> Option<usize> {
+    let line_start = if line <= 1 {
+        0
+    } else {
+        content.match_indices('\n').nth(line - 2).map(|(i, _)| i + 1)?
+    };
+    let line_end = content[line_start..].find('\n').map_or(content.len(), |i| line_start + i);
+    content[line_start..line_end]
+        .char_indices()
+        .map(|(i, _)| line_start + i)
+        .chain(std::iter::once(line_end))
+        .nth(column.saturating_sub(1))
+}
+
 fn fix_style_issue(issue: &Issue, content: &mut String, config: &Config) -> bool {
     let lines: Vec<&str> = content.lines().collect();
     
@@ -254,7 +448,7 @@ fn fix_style_issue(issue: &Issue, content: &mut String, config: &Config) -> bool
     let line = lines[line_idx];
     let mut fixed = false;
     let mut fixed_line = line.to_string();
-    if issue.message.contains("line too long ") && config.rules.syntax.max_line_length > 0 {
+    if issue.rule == Rule::LineTooLong && config.rules.syntax.max_line_length > 0 {
         let max_len = config.rules.syntax.max_line_length;
         if fixed_line.len() > max_len {
             if let Some(pos) = fixed_line[..max_len].rfind(", ") {
@@ -268,14 +462,14 @@ fn fix_style_issue(issue: &Issue, content: &mut String, config: &Config) -> bool
             }
         }
     }
-    else if issue.message.contains("missing space after control statement ") && config.rules.style.space_after_control_statements {
+    else if issue.rule == Rule::ControlStatementSpacing && config.rules.style.space_after_control_statements {
         let space_fix_regex = regex::Regex::new(r"(if|for|switch|select)\(").unwrap();
         if space_fix_regex.is_match(&fixed_line) {
             fixed_line = space_fix_regex.replace_all(&fixed_line, "$1 (").to_string();
             fixed = true;
         }
     }
-    else if issue.message.contains("should be camelCase ") && config.rules.style.enforce_camel_case {
+    else if issue.rule == Rule::CamelCase && config.rules.style.enforce_camel_case {
         let snake_case_regex = regex::Regex::new(r"\b([a-z]+)_([a-z][a-z0-9]*)\b").unwrap();
         if snake_case_regex.is_match(&fixed_line) {
             fixed_line = snake_case_regex.replace_all(&fixed_line, |caps: &regex::Captures| {
@@ -289,12 +483,12 @@ fn fix_style_issue(issue: &Issue, content: &mut String, config: &Config) -> bool
             fixed = true;
         }
     }
-    else if issue.message.contains("Use tabs for indentation ") {
+    else if issue.rule == Rule::TabIndentation {
         let leading_spaces_regex = regex::Regex::new(r"^( +)").unwrap();
         if let Some(captures) = leading_spaces_regex.captures(&fixed_line) {
             if let Some(spaces) = captures.get(1) {
                 let num_spaces = spaces.as_str().len();
-                let num_tabs = (num_spaces + 3) / 4;
+                let num_tabs = num_spaces.div_ceil(4);
                 let tabs = "\t".repeat(num_tabs);
                 fixed_line = leading_spaces_regex.replace(&fixed_line, tabs.as_str()).to_string();
                 fixed = true;
@@ -305,11 +499,11 @@ fn fix_style_issue(issue: &Issue, content: &mut String, config: &Config) -> bool
         let mut result = String::new();
         if line_idx > 0 {
             result.push_str(&lines[..line_idx].join("\n"));
-            result.push_str("\n");
+            result.push('\n');
         }
         result.push_str(&fixed_line);
         if line_idx < lines.len() - 1 {
-            result.push_str("\n");
+            result.push('\n');
             result.push_str(&lines[(line_idx + 1)..].join("\n"));
         }
         
@@ -317,4 +511,115 @@ fn fix_style_issue(issue: &Issue, content: &mut String, config: &Config) -> bool
     }
     
     fixed
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{issue, TempDir};
+
+    #[test]
+    fn renames_parameter_at_its_declaration() {
+        let source = "package p\n\nfunc f(x int, y int) int {\n\treturn y\n}\n";
+        let mut content = source.to_string();
+        let issue = issue(Path::new("p.go"), Rule::UnusedParameter, 3, 8, "x");
+        assert!(fix_issue(&issue, &mut content, &Config::default()));
+        assert_eq!(content, "package p\n\nfunc f(_ int, y int) int {\n\treturn y\n}\n");
+    }
+
+    #[test]
+    fn refuses_parameter_rename_when_token_moved() {
+        let source = "package p\n\nfunc f(x int, y int) int {\n\treturn y + 1\n}\n";
+        let mut content = source.to_string();
+        let issue = issue(Path::new("p.go"), Rule::UnusedParameter, 4, 9, "x");
+        assert!(!fix_issue(&issue, &mut content, &Config::default()));
+        assert_eq!(content, source);
+    }
+
+    #[test]
+    fn applies_fixes_bottom_up() {
+        let long = format!("var s = []string{{{}}}", vec!["\"abcdefgh\""; 14].join(", "));
+        let source = format!("package p\n\n{}\n\nfunc f(x int, y int) int {{\n\treturn y + 1\n}}\n", long);
+        let dir = TempDir::new("fixes-order");
+        let path = dir.write("p.go", &source);
+        let issues = vec![
+            issue(&path, Rule::LineTooLong, 3, 121, ""),
+            issue(&path, Rule::UnusedParameter, 5, 8, "x"),
+        ];
+        let fixed = apply_fixes(&path, &issues, &Config::default()).unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+
+        assert_eq!(fixed, 2);
+        assert!(content.contains("func f(_ int, y int) int {\n\treturn y + 1\n}"));
+    }
+
+    #[test]
+    fn counts_only_fixes_that_change_the_file() {
+        let source = "package p\n\nfunc f(x int) {}\n";
+        let dir = TempDir::new("fixes-count");
+        let path = dir.write("p.go", source);
+        let issues = vec![issue(&path, Rule::UnusedParameter, 3, 8, "y")];
+        let fixed = apply_fixes(&path, &issues, &Config::default()).unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+
+        assert_eq!(fixed, 0);
+        assert_eq!(content, source);
+    }
+
+    #[test]
+    fn removes_unused_function_with_its_doc_comment() {
+        let mut content = "package p\n\n// helper prints.\n// It is unused.\nfunc helper() {\n\tprintln(1)\n}\n\nfunc Used() {}\n".to_string();
+        let unused = issue(Path::new("p.go"), Rule::UnusedFunction, 5, 1, "helper");
+        assert!(fix_issue(&unused, &mut content, &Config::default()));
+        assert_eq!(content, "package p\n\nfunc Used() {}\n");
+
+        let mut content = "package p\n\nfunc Used() {}\n\nfunc helper() {\n}\n".to_string();
+        let unused = issue(Path::new("p.go"), Rule::UnusedFunction, 5, 1, "func helper() {\n}");
+        assert!(fix_issue(&unused, &mut content, &Config::default()));
+        assert_eq!(content, "package p\n\nfunc Used() {}\n");
+    }
+
+    #[test]
+    fn refuses_to_remove_a_function_that_moved() {
+        let source = "package p\n\nfunc Used() {}\n\nfunc helper() {}\n";
+        for (line, column) in [(3, 1), (4, 1), (5, 2)] {
+            let mut content = source.to_string();
+            let unused = issue(Path::new("p.go"), Rule::UnusedFunction, line, column, "helper");
+            assert!(!fix_issue(&unused, &mut content, &Config::default()));
+            assert_eq!(content, source);
+        }
+
+        let source = "package p\n\nfunc helper() {} // keep the comment\nvar x = 1; func other() {}\n";
+        let mut content = source.to_string();
+        let unused = issue(Path::new("p.go"), Rule::UnusedFunction, 4, 12, "other");
+        assert!(!fix_issue(&unused, &mut content, &Config::default()));
+        assert_eq!(content, source);
+    }
+
+    #[test]
+    fn removes_side_effect_free_local_variables() {
+        let mut content = "package p\n\nfunc f() {\n\tx := 1\n\t// y is left over.\n\tvar y = []int{x}\n\tprintln()\n}\n".to_string();
+        let config = Config::default();
+        assert!(fix_issue(&issue(Path::new("p.go"), Rule::UnusedVariable, 6, 6, "y"), &mut content, &config));
+        assert!(fix_issue(&issue(Path::new("p.go"), Rule::UnusedVariable, 4, 2, "x"), &mut content, &config));
+        assert_eq!(content, "package p\n\nfunc f() {\n\tprintln()\n}\n");
+    }
+
+    #[test]
+    fn keeps_variables_whose_removal_would_change_behaviour() {
+        for (line, column, body) in [
+            (4, 2, "\tx := compute()"),
+            (4, 2, "\tx := <-ch"),
+            (4, 2, "\tx, y := 1, 2"),
+            (4, 5, "\tif x := 1; true {\n\t}"),
+            (5, 3, "\tvar (\n\t\tx = 1\n\t\ty = 2\n\t)"),
+        ] {
+            let source = format!("package p\n\nfunc f(ch chan int) {{\n{}\n}}\n", body);
+            let mut content = source.clone();
+            let unused = issue(Path::new("p.go"), Rule::UnusedVariable, line, column, "x");
+            assert!(!fix_issue(&unused, &mut content, &Config::default()), "{}", body);
+            assert_eq!(content, source);
+        }
+    }
+}
+
@@ -0,0 +1,78 @@
+use anyhow::{Context, Result};
+use regex::Regex;
+
+#[derive(Debug, Clone)]
+pub struct Glob {
+    regex: Regex,
+}
+
+impl Glob {
+    pub fn new(pattern: &str) -> Result<Glob> {
+        let mut expression = String::from("^");
+        let mut rest = pattern;
+        while let Some(c) = rest.chars().next() {
+            if let Some(after) = rest.strip_prefix("**/") {
+                expression.push_str("(?:.*/)?");
+                rest = after;
+            } else if rest == "/**" {
+                expression.push_str("(?:/.*)?");
+                rest = "";
+            } else if let Some(after) = rest.strip_prefix("**") {
+                expression.push_str(".*");
+                rest = after;
+            } else {
+                match c {
+                    '*' => expression.push_str("[^/]*"),
+                    '?' => expression.push_str("[^/]"),
+                    _ => expression.push_str(&regex::escape(&c.to_string())),
+                }
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+        expression.push('$');
+
+        let regex = Regex::new(&expression).with_context(|| format!("Invalid glob pattern: {}", pattern))?;
+        Ok(Glob { regex })
+    }
+
+    pub fn is_match(&self, path: &str) -> bool {
+        self.regex.is_match(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(pattern: &str, path: &str) -> bool {
+        Glob::new(pattern).unwrap().is_match(path)
+    }
+
+    #[test]
+    fn single_star_stays_within_a_segment() {
+        assert!(matches("internal/*", "internal/db"));
+        assert!(!matches("internal/*", "internal/db/sql"));
+        assert!(matches("os.*", "os.Open"));
+        assert!(matches("file?.go", "file1.go"));
+        assert!(!matches("file?.go", "file/.go"));
+    }
+
+    #[test]
+    fn double_star_spans_segments() {
+        assert!(matches("**/testdata/**", "testdata"));
+        assert!(matches("**/testdata/**", "a/b/testdata/c/d"));
+        assert!(matches("example.com/m/**", "example.com/m"));
+        assert!(matches("example.com/m/**", "example.com/m/api/v1"));
+        assert!(!matches("example.com/m/**", "example.com/mx"));
+        assert!(matches("a**z", "a/b/z"));
+    }
+
+    #[test]
+    fn escapes_regex_metacharacters() {
+        assert!(matches("(*os.File).*", "(*os.File).Close"));
+        assert!(!matches("(*os.File).*", "os.File.Close"));
+        assert!(matches("a.b+c", "a.b+c"));
+        assert!(!matches("a.b+c", "axbbc"));
+        assert!(!matches("db", "internal/db"));
+    }
+}
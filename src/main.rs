@@ -4,9 +4,23 @@ use std::path::PathBuf;
 use std::process;
 
 mod analyzer;
+mod baseline;
 mod parser;
+mod project;
 mod fixes;
 mod config;
+mod glob;
+mod report;
+#[cfg(test)]
+mod test_support;
+
+use analyzer::Severity;
+use report::graph::{GraphFormat, GraphOptions};
+use report::metrics::MetricsFormat;
+use report::OutputFormat;
+
+const EXIT_ISSUES_FOUND: i32 = 1;
+const EXIT_TOOL_ERROR: i32 = 2;
 
 #[derive(Parser)]
 #[clap(author, version, about)]
@@ -24,11 +38,75 @@ enum Commands {
         fix: bool,
         #[clap(long, short, value_parser)]
         config: Option<PathBuf>,
+        #[clap(long, value_enum, default_value = "text")]
+        format: OutputFormat,
+        #[clap(long, value_parser, conflicts_with = "write_baseline")]
+        baseline: Option<PathBuf>,
+        #[clap(long, value_parser)]
+        write_baseline: Option<PathBuf>,
+        #[clap(long, value_enum)]
+        fail_on: Option<Severity>,
+        #[clap(long, short, value_parser)]
+        jobs: Option<usize>,
+    },
+    Graph {
+        #[clap(value_parser)]
+        path: PathBuf,
+        #[clap(long, short, value_parser)]
+        config: Option<PathBuf>,
+        #[clap(long, value_enum, default_value = "dot")]
+        format: GraphFormat,
+        #[clap(long, value_parser)]
+        depth: Option<usize>,
+        #[clap(long, value_parser)]
+        filter: Option<String>,
+        #[clap(long, short, value_parser)]
+        jobs: Option<usize>,
+    },
+    Metrics {
+        #[clap(value_parser)]
+        path: PathBuf,
+        #[clap(long, short, value_parser)]
+        config: Option<PathBuf>,
+        #[clap(long, value_enum, default_value = "text")]
+        format: MetricsFormat,
+        #[clap(long, short, value_parser)]
+        jobs: Option<usize>,
     },
     Init {
         #[clap(value_parser)]
         path: Option<PathBuf>,
     },
+    #[clap(hide = true)]
+    GenStdlib {
+        #[clap(long, value_parser)]
+        goroot: Option<PathBuf>,
+        #[clap(long, short, value_parser)]
+        output: PathBuf,
+    },
+}
+
+fn status(format: OutputFormat, message: String) {
+    if format.is_machine_readable() {
+        eprintln!("{}", message);
+    } else {
+        println!("{}", message);
+    }
+}
+
+fn load_config_or_exit(config: Option<PathBuf>) -> config::Config {
+    let config_path = match config {
+        Some(path) => path,
+        None => config::find_default_config(),
+    };
+    
+    match config::load_config(&config_path) {
+        Ok(cfg) => cfg,
+        Err(e) => {
+            eprintln!("{} Failed to load configuration: {}", "ERROR ".red().bold(), e);
+            process::exit(EXIT_TOOL_ERROR);
+        }
+    }
 }
 
 fn main() {
@@ -36,54 +114,113 @@ fn main() {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Lint { path, fix, config } => {
-            println!("{} Analyzing Go code at: {}", "DIOXIDE ".green().bold(), path.display());
-            let config_path = match config {
-                Some(path) => path,
-                None => config::find_default_config(),
-            };
-            
-            let config = match config::load_config(&config_path) {
-                Ok(cfg) => cfg,
-                Err(e) => {
-                    eprintln!("{} Failed to load configuration: {}", "ERROR ".red().bold(), e);
-                    process::exit(1);
-                }
+        Commands::Lint { path, fix, config, format, baseline, write_baseline, fail_on, jobs } => {
+            if !format.is_machine_readable() {
+                println!("{} Analyzing Go code at: {}", "DIOXIDE ".green().bold(), path.display());
+            }
+            let config = load_config_or_exit(config);
+            let baseline = match baseline {
+                Some(baseline_path) => match baseline::load(&baseline_path) {
+                    Ok(baseline) => Some(baseline),
+                    Err(e) => {
+                        eprintln!("{} Failed to load baseline: {}", "ERROR ".red().bold(), e);
+                        process::exit(EXIT_TOOL_ERROR);
+                    }
+                },
+                None => None,
             };
-            match analyzer::run_analysis(&path, &config) {
+            match analyzer::run_analysis(&path, &config, jobs) {
                 Ok(issues) => {
-                    if issues.is_empty() {
-                        println!("{} No issues found!", "SUCCESS ".green().bold());
-                    } else {
-                        println!("{} Found {} issues ", "WARNING ".yellow().bold(), issues.len());
-                        
-                        for issue in &issues {
-                            issue.print();
+                    if let Some(baseline_path) = write_baseline {
+                        let snapshot = baseline::Baseline::from_issues(&issues);
+                        if let Err(e) = baseline::write(&baseline_path, &snapshot) {
+                            eprintln!("{} Failed to write baseline: {}", "ERROR ".red().bold(), e);
+                            process::exit(EXIT_TOOL_ERROR);
+                        }
+                        status(format, format!("{} Wrote baseline with {} issues to: {}", "SUCCESS ".green().bold(), snapshot.len(), baseline_path.display()));
+                        return;
+                    }
+                    let issues = match &baseline {
+                        Some(baseline) => {
+                            let (new_issues, known) = baseline.filter_new(issues);
+                            if known > 0 && !format.is_machine_readable() {
+                                println!("{} Ignoring {} issues already recorded in the baseline ", "BASELINE ".blue().bold(), known);
+                            }
+                            new_issues
                         }
-                        
-                        if fix {
-                            println!("{} Attempting to fix issues...", "AUTOFIX ".blue().bold());
-                            match fixes::apply_fixes(&path, &issues, &config) {
-                                Ok(fixed) => {
-                                    if fixed > 0 {
-                                        println!("{} Fixed {}/{} issues ", "SUCCESS ".green().bold(), fixed, issues.len());
-                                    } else {
-                                        println!("{} No issues could be fixed automatically. This may be due to complex code patterns or issues that require manual intervention.", "WARNING ".yellow().bold());
-                                    }
+                        None => issues,
+                    };
+                    match format {
+                        OutputFormat::Text => {
+                            if issues.is_empty() {
+                                println!("{} No issues found!", "SUCCESS ".green().bold());
+                            } else {
+                                println!("{} Found {} issues ", "WARNING ".yellow().bold(), issues.len());
+                                
+                                for issue in &issues {
+                                    issue.print();
                                 }
-                                Err(e) => {
-                                    eprintln!("{} Failed to apply fixes: {}", "ERROR ".red().bold(), e);
+                            }
+                        }
+                        OutputFormat::Json | OutputFormat::Sarif => {
+                            let printed = match format {
+                                OutputFormat::Sarif => report::sarif::print(&issues, &config),
+                                _ => report::json::print(&issues),
+                            };
+                            if let Err(e) = printed {
+                                eprintln!("{} Failed to write report: {}", "ERROR ".red().bold(), e);
+                                process::exit(EXIT_TOOL_ERROR);
+                            }
+                        }
+                    }
+                    
+                    if fix && !issues.is_empty() {
+                        status(format, format!("{} Attempting to fix issues...", "AUTOFIX ".blue().bold()));
+                        match fixes::apply_fixes(&path, &issues, &config) {
+                            Ok(fixed) => {
+                                if fixed > 0 {
+                                    status(format, format!("{} Fixed {}/{} issues ", "SUCCESS ".green().bold(), fixed, issues.len()));
+                                } else {
+                                    status(format, format!("{} No issues could be fixed automatically. This may be due to complex code patterns or issues that require manual intervention.", "WARNING ".yellow().bold()));
                                 }
                             }
+                            Err(e) => {
+                                eprintln!("{} Failed to apply fixes: {}", "ERROR ".red().bold(), e);
+                                process::exit(EXIT_TOOL_ERROR);
+                            }
                         }
                     }
+                    
+                    let threshold = fail_on.unwrap_or(config.general.fail_on);
+                    if issues.iter().any(|issue| issue.severity.is_at_least(threshold)) {
+                        process::exit(EXIT_ISSUES_FOUND);
+                    }
                 }
                 Err(e) => {
                     eprintln!("{} Analysis failed: {}", "ERROR ".red().bold(), e);
-                    process::exit(1);
+                    process::exit(EXIT_TOOL_ERROR);
                 }
             }
         }
+        Commands::Graph { path, config, format, depth, filter, jobs } => {
+            let config = load_config_or_exit(config);
+            let options = GraphOptions { depth, filter };
+            let result = analyzer::build_project(&path, &config, jobs)
+                .and_then(|project| report::graph::print(&project, format, &options));
+            if let Err(e) = result {
+                eprintln!("{} Failed to build dependency graph: {}", "ERROR ".red().bold(), e);
+                process::exit(EXIT_TOOL_ERROR);
+            }
+        }
+        Commands::Metrics { path, config, format, jobs } => {
+            let config = load_config_or_exit(config);
+            let result = analyzer::build_project(&path, &config, jobs)
+                .and_then(|project| report::metrics::print(&project::metrics::compute(&project), format));
+            if let Err(e) = result {
+                eprintln!("{} Failed to compute package metrics: {}", "ERROR ".red().bold(), e);
+                process::exit(EXIT_TOOL_ERROR);
+            }
+        }
         Commands::Init { path } => {
             let config_path = path.unwrap_or_else(|| PathBuf::from("dioxide.toml "));
             match config::create_default_config(&config_path) {
@@ -92,7 +229,33 @@ fn main() {
                 }
                 Err(e) => {
                     eprintln!("{} Failed to create configuration: {}", "ERROR ".red().bold(), e);
-                    process::exit(1);
+                    process::exit(EXIT_TOOL_ERROR);
+                }
+            }
+        }
+        Commands::GenStdlib { goroot, output } => {
+            let Some(goroot) = goroot.or_else(|| std::env::var_os("GOROOT").map(PathBuf::from)) else {
+                eprintln!("{} No GOROOT given; pass --goroot or set GOROOT", "ERROR ".red().bold());
+                process::exit(EXIT_TOOL_ERROR);
+            };
+            let result = project::stdlib::generate(&goroot, &output).and_then(|_| {
+                let content = std::fs::read_to_string(&output)?;
+                project::stdlib::Stdlib::parse(&content)
+            });
+            match result {
+                Ok(stdlib) => {
+                    println!(
+                        "{} Wrote {} symbols ({} deprecated) for Go {} to: {}",
+                        "SUCCESS ".green().bold(),
+                        stdlib.symbols().count(),
+                        stdlib.symbols().filter(|symbol| symbol.deprecated.is_some()).count(),
+                        stdlib.go_version,
+                        output.display()
+                    );
+                }
+                Err(e) => {
+                    eprintln!("{} Failed to generate stdlib table: {:#}", "ERROR ".red().bold(), e);
+                    process::exit(EXIT_TOOL_ERROR);
                 }
             }
         }
@@ -1,9 +1,8 @@
 use anyhow::{Context, Result};
 use std::fs;
 use std::path::Path;
-use tree_sitter::{Parser, Tree};
+use tree_sitter::{Node, Parser, Tree};
 pub struct GoFile {
-    pub path: std::path::PathBuf,
     pub content: String,
     pub tree: Tree,
 }
@@ -35,7 +34,7 @@ impl GoFile {
         
         self.content[start_byte..end_byte].to_string()
     }
-    pub fn find_nodes(&self, node_type: &str) -> Vec<tree_sitter::Node> {
+    pub fn find_nodes(&self, node_type: &str) -> Vec<tree_sitter::Node<'_>> {
         let mut cursor = tree_sitter::QueryCursor::new();
         let query = tree_sitter::Query::new(
             tree_sitter_go::language(),
@@ -46,6 +45,11 @@ impl GoFile {
         matches.map(|m| m.captures[0].node).collect()
     }
 }
+pub fn named_children(node: Node) -> Vec<Node> {
+    let mut cursor = node.walk();
+    let children: Vec<Node> = node.named_children(&mut cursor).collect();
+    children
+}
 pub fn init_parser() -> Result<Parser> {
     let mut parser = Parser::new();
     parser.set_language(tree_sitter_go::language())
@@ -62,7 +66,6 @@ pub fn parse_file(path: &Path) -> Result<GoFile> {
         .context("Failed to parse Go file ")?;
     
     Ok(GoFile {
-        path: path.to_path_buf(),
         content,
         tree,
     })
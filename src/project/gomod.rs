@@ -0,0 +1,161 @@
+use anyhow::{Context, Result};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::modfile::{self, Replace};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleVersion {
+    pub path: String,
+    pub version: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Require {
+    pub module: ModuleVersion,
+    pub indirect: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct GoMod {
+    pub dir: PathBuf,
+    pub module: String,
+    pub go_version: Option<String>,
+    pub requires: Vec<Require>,
+    pub replaces: Vec<Replace>,
+    pub excludes: Vec<ModuleVersion>,
+}
+
+impl GoMod {
+    pub fn load(dir: &Path) -> Result<GoMod> {
+        let path = dir.join("go.mod");
+        let content =
+            fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+        GoMod::parse(dir, &content).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn parse(dir: &Path, content: &str) -> Result<GoMod> {
+        let mut module = None;
+        let mut go_mod = GoMod {
+            dir: dir.to_path_buf(),
+            module: String::new(),
+            go_version: None,
+            requires: Vec::new(),
+            replaces: Vec::new(),
+            excludes: Vec::new(),
+        };
+
+        for directive in modfile::parse(content)? {
+            let line_number = directive.line;
+            match (directive.verb.as_str(), &directive.args[..]) {
+                ("module", [path]) => module = Some(path.clone()),
+                ("go", [version]) => go_mod.go_version = Some(version.clone()),
+                ("require", [path, version]) => go_mod.requires.push(Require {
+                    module: ModuleVersion {
+                        path: path.clone(),
+                        version: version.clone(),
+                    },
+                    indirect: directive.comment.split_whitespace().next() == Some("indirect"),
+                }),
+                ("exclude", [path, version]) => go_mod.excludes.push(ModuleVersion {
+                    path: path.clone(),
+                    version: version.clone(),
+                }),
+                ("replace", args) => go_mod.replaces.push(modfile::parse_replace(args, line_number)?),
+                ("module" | "go" | "require" | "exclude", _) => {
+                    return Err(anyhow::anyhow!("line {}: malformed {} directive", line_number, directive.verb));
+                }
+                _ => {}
+            }
+        }
+
+        go_mod.module = module.ok_or_else(|| anyhow::anyhow!("missing module directive"))?;
+
+        Ok(go_mod)
+    }
+
+    pub fn import_path_for_dir(&self, dir: &Path) -> Option<String> {
+        let relative = dir.strip_prefix(&self.dir).ok()?;
+        let mut import_path = self.module.clone();
+        for component in relative.components() {
+            import_path.push('/');
+            import_path.push_str(&component.as_os_str().to_string_lossy());
+        }
+
+        Some(import_path)
+    }
+}
+
+impl fmt::Display for GoMod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "module {}", self.module)?;
+        if let Some(version) = &self.go_version {
+            writeln!(f, "go {}", version)?;
+        }
+        for require in &self.requires {
+            write!(f, "require {} {}", require.module.path, require.module.version)?;
+            if require.indirect {
+                write!(f, " // indirect")?;
+            }
+            writeln!(f)?;
+        }
+        for replace in &self.replaces {
+            writeln!(f, "{}", replace)?;
+        }
+        for exclude in &self.excludes {
+            writeln!(f, "exclude {} {}", exclude.path, exclude.version)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_directives_and_blocks() {
+        let content = "// Example module.\nmodule \"example.com/m\" // the module\n\ngo 1.22\n\nrequire (\n\tgolang.org/x/mod v0.14.0\n\tgolang.org/x/sys v0.15.0 // indirect\n)\n\nrequire github.com/pkg/errors v0.9.1\n\nreplace golang.org/x/mod => ../mod\n\nexclude (\n\tgolang.org/x/sys v0.1.0\n)\n\ntoolchain go1.22.1\n";
+        let go_mod = GoMod::parse(Path::new("/src/m"), content).unwrap();
+
+        assert_eq!(go_mod.module, "example.com/m");
+        assert_eq!(go_mod.go_version.as_deref(), Some("1.22"));
+        let requires: Vec<(&str, bool)> = go_mod
+            .requires
+            .iter()
+            .map(|require| (require.module.path.as_str(), require.indirect))
+            .collect();
+        assert_eq!(
+            requires,
+            [
+                ("golang.org/x/mod", false),
+                ("golang.org/x/sys", true),
+                ("github.com/pkg/errors", false),
+            ]
+        );
+        assert_eq!(go_mod.replaces[0].to_string(), "replace golang.org/x/mod => ../mod");
+        assert_eq!(go_mod.excludes[0].version, "v0.1.0");
+    }
+
+    #[test]
+    fn rejects_malformed_files() {
+        let dir = Path::new("/src/m");
+        assert!(GoMod::parse(dir, "go 1.22\n").is_err());
+        assert!(GoMod::parse(dir, "module a b\n").is_err());
+        assert!(GoMod::parse(dir, "module m\nrequire (\n\ta v1.0.0\n").is_err());
+        assert!(GoMod::parse(dir, "module m\nreplace a v1.0.0 ../a\n").is_err());
+    }
+
+    #[test]
+    fn maps_directories_to_import_paths() {
+        let go_mod = GoMod::parse(Path::new("/src/m"), "module example.com/m\n").unwrap();
+        assert_eq!(go_mod.import_path_for_dir(Path::new("/src/m")).as_deref(), Some("example.com/m"));
+        assert_eq!(
+            go_mod.import_path_for_dir(Path::new("/src/m/internal/db")).as_deref(),
+            Some("example.com/m/internal/db")
+        );
+        assert_eq!(go_mod.import_path_for_dir(Path::new("/src/other")), None);
+    }
+}
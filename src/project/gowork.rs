@@ -0,0 +1,108 @@
+use anyhow::{Context, Result};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::modfile::{self, Replace};
+
+#[derive(Debug, Clone)]
+pub struct GoWork {
+    pub dir: PathBuf,
+    pub go_version: Option<String>,
+    pub uses: Vec<String>,
+    pub replaces: Vec<Replace>,
+}
+
+impl GoWork {
+    pub fn load(dir: &Path) -> Result<GoWork> {
+        let path = dir.join("go.work");
+        let content =
+            fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+        GoWork::parse(dir, &content).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn parse(dir: &Path, content: &str) -> Result<GoWork> {
+        let mut go_work = GoWork {
+            dir: dir.to_path_buf(),
+            go_version: None,
+            uses: Vec::new(),
+            replaces: Vec::new(),
+        };
+
+        for directive in modfile::parse(content)? {
+            let line_number = directive.line;
+            match (directive.verb.as_str(), &directive.args[..]) {
+                ("go", [version]) => go_work.go_version = Some(version.clone()),
+                ("use", [path]) => go_work.uses.push(path.clone()),
+                ("replace", args) => go_work.replaces.push(modfile::parse_replace(args, line_number)?),
+                ("go" | "use", _) => {
+                    return Err(anyhow::anyhow!("line {}: malformed {} directive", line_number, directive.verb));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(go_work)
+    }
+
+    pub fn module_dirs(&self) -> Vec<PathBuf> {
+        self.uses
+            .iter()
+            .map(|path| super::canonical(&self.dir.join(path)))
+            .filter(|dir| {
+                let has_go_mod = dir.join("go.mod").is_file();
+                if !has_go_mod {
+                    log::warn!("Ignoring go.work entry without a go.mod: {}", dir.display());
+                }
+                has_go_mod
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for GoWork {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(version) = &self.go_version {
+            writeln!(f, "go {}", version)?;
+        }
+        for path in &self.uses {
+            writeln!(f, "use {}", path)?;
+        }
+        for replace in &self.replaces {
+            writeln!(f, "{}", replace)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    #[test]
+    fn parses_use_and_replace_directives() {
+        let content = "go 1.22\n\nuse (\n\t./api\n\t\"./cmd\" // tools\n)\n\nuse ./lib\n\nreplace example.com/a v1.0.0 => ./a\n";
+        let go_work = GoWork::parse(Path::new("/src/w"), content).unwrap();
+        assert_eq!(go_work.go_version.as_deref(), Some("1.22"));
+        assert_eq!(go_work.uses, ["./api", "./cmd", "./lib"]);
+        assert_eq!(go_work.replaces[0].new_path, "./a");
+
+        assert!(GoWork::parse(Path::new("/src/w"), "use ./a ./b\n").is_err());
+        assert!(GoWork::parse(Path::new("/src/w"), "go\n").is_err());
+    }
+
+    #[test]
+    fn ignores_uses_without_go_mod() {
+        let dir = TempDir::new("gowork");
+        dir.write("api/go.mod", "module example.com/api\n");
+        dir.write("docs/README.md", "");
+
+        let go_work = GoWork::parse(dir.path(), "use (\n\t./api\n\t./docs\n\t./missing\n)\n").unwrap();
+        let dirs = go_work.module_dirs();
+
+        assert_eq!(dirs.len(), 1);
+        assert!(dirs[0].ends_with("api"));
+    }
+}
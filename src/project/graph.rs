@@ -0,0 +1,219 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+
+#[derive(Debug, Default, Clone)]
+pub struct ImportGraph {
+    edges: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl ImportGraph {
+    pub fn add_node(&mut self, package: &str) {
+        self.edges.entry(package.to_string()).or_default();
+    }
+
+    pub fn add_edge(&mut self, from: &str, to: &str) {
+        self.add_node(to);
+        self.edges.entry(from.to_string()).or_default().insert(to.to_string());
+    }
+
+    pub fn dependencies(&self, package: &str) -> impl Iterator<Item = &String> {
+        self.edges.get(package).into_iter().flatten()
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &String> {
+        self.edges.keys()
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.edges
+            .iter()
+            .flat_map(|(from, deps)| deps.iter().map(move |to| (from, to)))
+    }
+
+    pub fn retain_nodes(&self, keep: impl Fn(&str) -> bool) -> ImportGraph {
+        let mut graph = ImportGraph::default();
+        for node in self.nodes().filter(|node| keep(node)) {
+            graph.add_node(node);
+            for dep in self.dependencies(node).filter(|dep| keep(dep)) {
+                graph.add_edge(node, dep);
+            }
+        }
+        graph
+    }
+
+    pub fn map_nodes(&self, rename: impl Fn(&str) -> String) -> ImportGraph {
+        let mut graph = ImportGraph::default();
+        for (node, deps) in &self.edges {
+            let from = rename(node);
+            graph.add_node(&from);
+            for dep in deps {
+                let to = rename(dep);
+                if to != from {
+                    graph.add_edge(&from, &to);
+                }
+            }
+        }
+        graph
+    }
+
+    pub fn strongly_connected_components(&self) -> Vec<Vec<String>> {
+        let mut tarjan = Tarjan::new(self);
+        for node in self.edges.keys() {
+            if !tarjan.index.contains_key(node.as_str()) {
+                tarjan.visit(node);
+            }
+        }
+
+        let mut components = tarjan.components;
+        for component in components.iter_mut() {
+            component.sort();
+        }
+        components.sort();
+        components
+    }
+
+    pub fn cyclic_components(&self) -> Vec<Vec<String>> {
+        self.strongly_connected_components()
+            .into_iter()
+            .filter(|component| {
+                component.len() > 1 || self.dependencies(&component[0]).any(|dep| dep == &component[0])
+            })
+            .collect()
+    }
+
+    pub fn cycles(&self) -> Vec<Vec<String>> {
+        self.cyclic_components()
+            .into_iter()
+            .filter_map(|component| self.shortest_cycle(&component))
+            .collect()
+    }
+
+    fn shortest_cycle(&self, component: &[String]) -> Option<Vec<String>> {
+        let start = component.first()?;
+        let members: BTreeSet<&str> = component.iter().map(String::as_str).collect();
+        let mut previous: HashMap<&str, &str> = HashMap::new();
+        let mut queue = VecDeque::from([start.as_str()]);
+
+        while let Some(current) = queue.pop_front() {
+            for dep in self.dependencies(current) {
+                if dep == start {
+                    let mut cycle = vec![current.to_string()];
+                    let mut node = current;
+                    while let Some(&prev) = previous.get(node) {
+                        cycle.push(prev.to_string());
+                        node = prev;
+                    }
+                    cycle.reverse();
+                    cycle.push(start.clone());
+                    return Some(cycle);
+                }
+                if members.contains(dep.as_str()) && !previous.contains_key(dep.as_str()) {
+                    previous.insert(dep, current);
+                    queue.push_back(dep);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+struct Tarjan<'a> {
+    graph: &'a ImportGraph,
+    next_index: usize,
+    index: HashMap<&'a str, usize>,
+    low_link: HashMap<&'a str, usize>,
+    stack: Vec<&'a str>,
+    on_stack: BTreeSet<&'a str>,
+    components: Vec<Vec<String>>,
+}
+
+impl<'a> Tarjan<'a> {
+    fn new(graph: &'a ImportGraph) -> Self {
+        Tarjan {
+            graph,
+            next_index: 0,
+            index: HashMap::new(),
+            low_link: HashMap::new(),
+            stack: Vec::new(),
+            on_stack: BTreeSet::new(),
+            components: Vec::new(),
+        }
+    }
+
+    fn visit(&mut self, node: &'a str) {
+        self.index.insert(node, self.next_index);
+        self.low_link.insert(node, self.next_index);
+        self.next_index += 1;
+        self.stack.push(node);
+        self.on_stack.insert(node);
+
+        for dep in self.graph.dependencies(node) {
+            if !self.index.contains_key(dep.as_str()) {
+                self.visit(dep);
+                let low = self.low_link[node].min(self.low_link[dep.as_str()]);
+                self.low_link.insert(node, low);
+            } else if self.on_stack.contains(dep.as_str()) {
+                let low = self.low_link[node].min(self.index[dep.as_str()]);
+                self.low_link.insert(node, low);
+            }
+        }
+
+        if self.low_link[node] == self.index[node] {
+            let mut component = Vec::new();
+            while let Some(member) = self.stack.pop() {
+                self.on_stack.remove(member);
+                component.push(member.to_string());
+                if member == node {
+                    break;
+                }
+            }
+            self.components.push(component);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(edges: &[(&str, &str)]) -> ImportGraph {
+        let mut graph = ImportGraph::default();
+        for (from, to) in edges {
+            graph.add_edge(from, to);
+        }
+        graph
+    }
+
+    #[test]
+    fn finds_strongly_connected_components() {
+        let graph = graph(&[("a", "b"), ("b", "c"), ("c", "a"), ("c", "d"), ("d", "e"), ("e", "d"), ("f", "a")]);
+        assert_eq!(
+            graph.strongly_connected_components(),
+            [vec!["a", "b", "c"], vec!["d", "e"], vec!["f"]]
+        );
+    }
+
+    #[test]
+    fn reports_one_shortest_cycle_per_component() {
+        let graph = graph(&[("a", "b"), ("b", "c"), ("c", "d"), ("d", "a"), ("b", "a"), ("x", "x")]);
+        assert_eq!(graph.cycles(), [vec!["a", "b", "a"], vec!["x", "x"]]);
+    }
+
+    #[test]
+    fn acyclic_graphs_have_no_cycles() {
+        let graph = graph(&[("a", "b"), ("a", "c"), ("b", "c")]);
+        assert!(graph.cycles().is_empty());
+    }
+
+    #[test]
+    fn collapsing_nodes_drops_self_edges() {
+        let graph = graph(&[("m/a/x", "m/a/y"), ("m/a/y", "m/b"), ("m/b", "m/a/x")]);
+        let collapsed = graph.map_nodes(|node| node.trim_end_matches("/x").trim_end_matches("/y").to_string());
+        assert_eq!(collapsed.cycles(), [vec!["m/a", "m/b", "m/a"]]);
+        assert_eq!(collapsed.cyclic_components(), [vec!["m/a", "m/b"]]);
+
+        let kept = graph.retain_nodes(|node| node != "m/b");
+        assert!(kept.cyclic_components().is_empty());
+        assert_eq!(kept.edges().count(), 1);
+    }
+}
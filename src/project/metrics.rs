@@ -0,0 +1,97 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use super::Project;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PackageMetrics {
+    pub package: String,
+    pub afferent: usize,
+    pub efferent: usize,
+    pub instability: f64,
+    pub interfaces: usize,
+    pub concrete_types: usize,
+    pub abstractness: f64,
+    pub distance: f64,
+}
+
+pub fn compute(project: &Project) -> Vec<PackageMetrics> {
+    let graph = project.import_graph();
+    let mut afferent: BTreeMap<&str, usize> = BTreeMap::new();
+    for (_, to) in graph.edges() {
+        *afferent.entry(to.as_str()).or_insert(0) += 1;
+    }
+
+    project
+        .packages
+        .values()
+        .map(|package| {
+            let afferent = afferent.get(package.path.as_str()).copied().unwrap_or(0);
+            let efferent = graph.dependencies(&package.path).count();
+            let instability = ratio(efferent, afferent + efferent);
+
+            let source_files = package.files.iter().filter(|file| !file.is_test());
+            let (interfaces, concrete_types) = source_files.fold((0, 0), |(interfaces, concrete), file| {
+                (interfaces + file.interfaces, concrete + file.concrete_types)
+            });
+            let abstractness = ratio(interfaces, interfaces + concrete_types);
+
+            PackageMetrics {
+                package: package.path.clone(),
+                afferent,
+                efferent,
+                instability,
+                interfaces,
+                concrete_types,
+                abstractness,
+                distance: (abstractness + instability - 1.0).abs(),
+            }
+        })
+        .collect()
+}
+
+fn ratio(part: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        part as f64 / total as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer;
+    use crate::config::Config;
+    use crate::test_support::TempDir;
+
+    #[test]
+    fn computes_coupling_and_abstractness() {
+        let dir = TempDir::new("metrics");
+        dir.write("go.mod", "module example.com/m\n");
+        dir.write("cmd/main.go", "package main\n\nimport _ \"example.com/m/core\"\n");
+        dir.write("api/api.go", "package api\n\nimport _ \"example.com/m/core\"\n");
+        dir.write(
+            "core/core.go",
+            "package core\n\nimport _ \"example.com/m/util\"\n\ntype Store interface{}\n\ntype memory struct{}\n",
+        );
+        dir.write("core/core_test.go", "package core\n\ntype fake struct{}\n");
+        dir.write("util/util.go", "package util\n\ntype Reader interface{}\n");
+        let project = analyzer::build_project(dir.path(), &Config::default(), None).unwrap();
+
+        let metrics = compute(&project);
+        let core = metrics.iter().find(|m| m.package == "example.com/m/core").unwrap();
+        assert_eq!((core.afferent, core.efferent), (2, 1));
+        assert_eq!((core.interfaces, core.concrete_types), (1, 1));
+        assert!((core.instability - 1.0 / 3.0).abs() < 1e-9);
+        assert_eq!(core.abstractness, 0.5);
+        assert!((core.distance - 1.0 / 6.0).abs() < 1e-9);
+
+        let util = metrics.iter().find(|m| m.package == "example.com/m/util").unwrap();
+        assert_eq!((util.afferent, util.efferent, util.instability, util.abstractness), (1, 0, 0.0, 1.0));
+        assert_eq!(util.distance, 0.0);
+
+        let main = metrics.iter().find(|m| m.package == "example.com/m/cmd").unwrap();
+        assert_eq!((main.instability, main.abstractness, main.distance), (1.0, 0.0, 0.0));
+    }
+}
@@ -0,0 +1,469 @@
+use rayon::prelude::*;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::parser::{self, GoFile};
+
+mod gomod;
+mod gowork;
+mod graph;
+pub mod metrics;
+mod modfile;
+pub mod stdlib;
+mod symbols;
+
+pub use gomod::GoMod;
+pub use stdlib::{GoVersion, StdlibSymbol};
+pub use gowork::GoWork;
+pub use graph::ImportGraph;
+pub use symbols::{declarations, references, spec_names, specs, Declaration, DeclarationKind};
+
+#[derive(Debug, Clone)]
+pub struct Import {
+    pub path: String,
+    pub alias: Option<String>,
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct SourceFile {
+    pub path: PathBuf,
+    pub package_name: String,
+    pub package_line: usize,
+    pub package_column: usize,
+    pub imports: Vec<Import>,
+    pub interfaces: usize,
+    pub concrete_types: usize,
+    pub declarations: Vec<Declaration>,
+    pub references: HashSet<String>,
+    pub qualified_references: HashSet<(String, String)>,
+    pub member_references: HashSet<String>,
+    pub interface_methods: HashSet<String>,
+    pub value_references: HashSet<String>,
+    pub unkeyed_literals: HashSet<String>,
+}
+
+impl SourceFile {
+    pub fn is_test(&self) -> bool {
+        self.path.to_string_lossy().ends_with("_test.go")
+    }
+
+    fn from_ast(path: &Path, ast: &GoFile) -> Self {
+        let mut imports = Vec::new();
+        for spec in ast.find_nodes("import_spec") {
+            if let Some(path_node) = spec.child_by_field_name("path") {
+                let import_path = ast.get_snippet(path_node.start_byte(), path_node.end_byte());
+                let (line, column) = ast.get_position(spec.start_byte());
+                imports.push(Import {
+                    path: import_path.trim_matches(|c| c == '"' || c == '`').to_string(),
+                    alias: spec
+                        .child_by_field_name("name")
+                        .map(|name| ast.get_snippet(name.start_byte(), name.end_byte())),
+                    line,
+                    column,
+                });
+            }
+        }
+
+        let package_clause = ast.find_nodes("package_clause").first().copied();
+        let (package_line, package_column) =
+            package_clause.map_or((1, 1), |node| ast.get_position(node.start_byte()));
+        let package_name = package_clause
+            .and_then(|node| node.named_child(0))
+            .map(|name| ast.get_snippet(name.start_byte(), name.end_byte()))
+            .unwrap_or_default();
+
+        let mut interfaces = 0;
+        let mut concrete_types = 0;
+        for spec in ast.find_nodes("type_spec") {
+            match spec.child_by_field_name("type").map(|t| t.kind()) {
+                Some("interface_type") => interfaces += 1,
+                Some(_) => concrete_types += 1,
+                None => {}
+            }
+        }
+
+        SourceFile {
+            path: path.to_path_buf(),
+            package_name,
+            package_line,
+            package_column,
+            imports,
+            interfaces,
+            concrete_types,
+            declarations: symbols::declarations(ast),
+            references: symbols::references(ast),
+            qualified_references: symbols::qualified_references(ast),
+            member_references: symbols::member_references(ast),
+            interface_methods: symbols::interface_methods(ast),
+            value_references: symbols::value_references(ast),
+            unkeyed_literals: symbols::unkeyed_literals(ast),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Package {
+    pub path: String,
+    pub module: Option<String>,
+    pub files: Vec<SourceFile>,
+}
+
+impl Package {
+    pub fn name(&self) -> Option<&str> {
+        self.files
+            .iter()
+            .find(|file| !file.is_test())
+            .or_else(|| self.files.first())
+            .map(|file| file.package_name.as_str())
+    }
+
+    pub fn relative_path(&self) -> &str {
+        match &self.module {
+            Some(module) if &self.path == module => "",
+            Some(module) => self
+                .path
+                .strip_prefix(module.as_str())
+                .and_then(|rest| rest.strip_prefix('/'))
+                .unwrap_or(&self.path),
+            None => &self.path,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Project {
+    pub modules: Vec<GoMod>,
+    pub packages: BTreeMap<String, Package>,
+    files: HashMap<PathBuf, String>,
+    interface_methods: HashSet<String>,
+    value_references: HashSet<String>,
+    error_signatures: HashMap<String, ErrorSignature>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorSignature {
+    pub name: String,
+    pub position: Option<usize>,
+}
+
+impl Project {
+    pub fn build(files: &[PathBuf]) -> Project {
+        let parsed: Vec<SourceFile> = files
+            .par_iter()
+            .filter_map(|path| match parser::parse_file(path) {
+                Ok(ast) => Some(SourceFile::from_ast(&canonical(path), &ast)),
+                Err(e) => {
+                    log::warn!("Skipping {} in project index: {}", path.display(), e);
+                    None
+                }
+            })
+            .collect();
+
+        let mut project = Project::default();
+        let mut module_dirs: HashMap<PathBuf, Option<usize>> = HashMap::new();
+        for file in parsed {
+            let dir = file.path.parent().unwrap_or(Path::new("")).to_path_buf();
+            let module = project.module_for_dir(&dir, &mut module_dirs);
+            let module_path = module.map(|module| module.module.clone());
+            let package_path = module
+                .and_then(|module| module.import_path_for_dir(&dir))
+                .unwrap_or_else(|| package_path_for(&file.path));
+            project.files.insert(file.path.clone(), package_path.clone());
+            project.interface_methods.extend(file.interface_methods.iter().cloned());
+            project.value_references.extend(file.value_references.iter().cloned());
+            for declaration in &file.declarations {
+                if let Some(position) = declaration.error_result {
+                    let name = signature_name(&package_path, declaration);
+                    let signature = ErrorSignature {
+                        name: name.clone(),
+                        position: Some(position),
+                    };
+                    project.error_signatures.insert(signature_key(&name), signature);
+                }
+            }
+            project
+                .packages
+                .entry(package_path.clone())
+                .or_insert_with(|| Package {
+                    path: package_path,
+                    module: module_path,
+                    files: Vec::new(),
+                })
+                .files
+                .push(file);
+        }
+        for package in project.packages.values_mut() {
+            package.files.sort_by(|a, b| a.path.cmp(&b.path));
+        }
+
+        project
+    }
+
+    fn module_for_dir(&mut self, dir: &Path, cache: &mut HashMap<PathBuf, Option<usize>>) -> Option<&GoMod> {
+        let mut visited = Vec::new();
+        let mut current = Some(dir);
+        let mut found = None;
+        while let Some(candidate) = current {
+            if let Some(cached) = cache.get(candidate) {
+                found = *cached;
+                break;
+            }
+            visited.push(candidate.to_path_buf());
+            if candidate.join("go.mod").is_file() {
+                match GoMod::load(candidate) {
+                    Ok(module) => {
+                        log::debug!("Loaded go.mod in {}:\n{}", candidate.display(), module);
+                        self.modules.push(module);
+                        found = Some(self.modules.len() - 1);
+                    }
+                    Err(e) => log::warn!("Ignoring go.mod: {:#}", e),
+                }
+                break;
+            }
+            current = candidate.parent();
+        }
+        for visited_dir in visited {
+            cache.insert(visited_dir, found);
+        }
+
+        found.map(|index| &self.modules[index])
+    }
+
+    pub fn package_of(&self, file: &Path) -> Option<&Package> {
+        let package_path = self.files.get(&canonical(file))?;
+        self.packages.get(package_path)
+    }
+
+    pub fn source_file(&self, file: &Path) -> Option<&SourceFile> {
+        let file = canonical(file);
+        self.package_of(&file)?.files.iter().find(|f| f.path == file)
+    }
+
+    pub fn import_qualifiers<'a>(&'a self, file: &'a SourceFile) -> HashMap<&'a str, &'a str> {
+        let mut qualifiers = HashMap::new();
+        for import in &file.imports {
+            match import.alias.as_deref() {
+                Some("_") | Some(".") => {}
+                Some(alias) => {
+                    qualifiers.insert(alias, import.path.as_str());
+                }
+                None => {
+                    let name = self
+                        .packages
+                        .get(&import.path)
+                        .and_then(|package| package.name())
+                        .unwrap_or_else(|| import.path.rsplit('/').next().unwrap_or(&import.path));
+                    qualifiers.insert(name, import.path.as_str());
+                }
+            }
+        }
+        qualifiers
+    }
+
+    pub fn package_files(&self, file: &Path) -> Option<Vec<&SourceFile>> {
+        let file = canonical(file);
+        let package = self.package_of(&file)?;
+        let package_name = &package.files.iter().find(|f| f.path == file)?.package_name;
+        Some(package.files.iter().filter(|f| &f.package_name == package_name).collect())
+    }
+
+    pub fn package_references(&self, file: &Path) -> Option<HashSet<&str>> {
+        let files = self.package_files(file)?;
+        Some(files.into_iter().flat_map(|f| f.references.iter().map(String::as_str)).collect())
+    }
+
+    pub fn interface_methods(&self) -> &HashSet<String> {
+        &self.interface_methods
+    }
+
+    pub fn value_references(&self) -> &HashSet<String> {
+        &self.value_references
+    }
+
+    pub fn error_signature(
+        &self,
+        file: &Path,
+        package: &str,
+        receiver: Option<&str>,
+        name: &str,
+    ) -> Option<ErrorSignature> {
+        let key = symbol_key(package, receiver, name);
+        self.error_signatures.get(&key).cloned().or_else(|| {
+            let symbol = stdlib::stdlib().lookup(&key, self.go_version(file))?;
+            Some(ErrorSignature {
+                name: symbol.name.clone(),
+                position: Some(symbol.error_position()?),
+            })
+        })
+    }
+
+    pub fn go_version(&self, file: &Path) -> Option<GoVersion> {
+        let module = self.package_of(file)?.module.as_deref()?;
+        let module = self.modules.iter().find(|m| m.module == module)?;
+        GoVersion::parse(module.go_version.as_deref()?)
+    }
+
+    pub fn stdlib_symbol(
+        &self,
+        file: &Path,
+        package: &str,
+        receiver: Option<&str>,
+        name: &str,
+    ) -> Option<&'static StdlibSymbol> {
+        stdlib::stdlib().lookup(&symbol_key(package, receiver, name), self.go_version(file))
+    }
+
+    pub fn collapsed_path(&self, package_path: &str, depth: usize) -> String {
+        let Some(package) = self.packages.get(package_path) else {
+            return truncate_path(package_path, depth);
+        };
+        match &package.module {
+            Some(module) => {
+                let relative = truncate_path(package.relative_path(), depth);
+                if relative.is_empty() {
+                    module.clone()
+                } else {
+                    format!("{}/{}", module, relative)
+                }
+            }
+            None => truncate_path(&package.path, depth),
+        }
+    }
+
+    pub fn import_graph(&self) -> ImportGraph {
+        let mut graph = ImportGraph::default();
+        for package in self.packages.values() {
+            graph.add_node(&package.path);
+            for file in package.files.iter().filter(|f| !f.is_test()) {
+                for import in &file.imports {
+                    if self.packages.contains_key(&import.path) {
+                        graph.add_edge(&package.path, &import.path);
+                    }
+                }
+            }
+        }
+
+        graph
+    }
+}
+
+fn truncate_path(path: &str, depth: usize) -> String {
+    let truncated = path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .take(depth)
+        .collect::<Vec<_>>()
+        .join("/");
+    if path.starts_with('/') {
+        format!("/{}", truncated)
+    } else {
+        truncated
+    }
+}
+
+fn symbol_key(package: &str, receiver: Option<&str>, name: &str) -> String {
+    match receiver {
+        Some(receiver) => format!("{}.{}.{}", package, receiver, name),
+        None => format!("{}.{}", package, name),
+    }
+}
+
+pub fn signature_key(name: &str) -> String {
+    name.replace("(*", "").replace(['(', ')'], "")
+}
+
+fn signature_name(package: &str, declaration: &Declaration) -> String {
+    match &declaration.receiver {
+        Some(receiver) => {
+            let pointer = if declaration.pointer_receiver { "*" } else { "" };
+            format!("({}{}.{}).{}", pointer, package, receiver, declaration.name)
+        }
+        None => format!("{}.{}", package, declaration.name),
+    }
+}
+
+pub fn canonical(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+pub fn package_path_for(file_path: &Path) -> String {
+    let path_str = file_path.to_string_lossy();
+    if let Some(src_idx) = path_str.find("/src/") {
+        let after_src = &path_str[src_idx + 5..];
+        let dir_path = Path::new(after_src).parent().unwrap_or(Path::new(""));
+        return dir_path.to_string_lossy().to_string();
+    }
+    file_path
+        .parent()
+        .unwrap_or(Path::new(""))
+        .to_string_lossy()
+        .to_string()
+}
+
+pub fn find_project_root(path: &Path) -> PathBuf {
+    let path = canonical(path);
+    let start = if path.is_file() {
+        path.parent().unwrap_or(Path::new("")).to_path_buf()
+    } else {
+        path.clone()
+    };
+
+    if let Some(workspace) = find_workspace(&start) {
+        return workspace;
+    }
+
+    let mut current = start.as_path();
+    loop {
+        if current.join("go.mod").exists() {
+            return current.to_path_buf();
+        }
+        let src_dir = current.join("src");
+        if src_dir.exists() && src_dir.is_dir() {
+            return current.to_path_buf();
+        }
+        match current.parent() {
+            Some(parent) => current = parent,
+            None => break,
+        }
+    }
+    start
+}
+
+fn find_workspace(start: &Path) -> Option<PathBuf> {
+    match std::env::var("GOWORK") {
+        Ok(value) if value == "off" => return None,
+        Ok(value) if !value.is_empty() => {
+            return Path::new(&value).parent().map(canonical);
+        }
+        _ => {}
+    }
+
+    start
+        .ancestors()
+        .find(|dir| dir.join("go.work").is_file())
+        .map(Path::to_path_buf)
+}
+
+pub fn source_roots(root: &Path) -> Vec<PathBuf> {
+    let mut roots = vec![root.to_path_buf()];
+    if !root.join("go.work").is_file() {
+        return roots;
+    }
+
+    match GoWork::load(root) {
+        Ok(workspace) => {
+            log::debug!("Loaded go.work in {}:\n{}", root.display(), workspace);
+            for dir in workspace.module_dirs() {
+                if !dir.starts_with(root) {
+                    roots.push(dir);
+                }
+            }
+        }
+        Err(e) => log::warn!("Ignoring go.work: {:#}", e),
+    }
+
+    roots
+}
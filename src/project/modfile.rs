@@ -0,0 +1,197 @@
+use anyhow::Result;
+use std::fmt;
+
+#[derive(Debug, Clone)]
+pub struct Directive {
+    pub line: usize,
+    pub verb: String,
+    pub args: Vec<String>,
+    pub comment: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replace {
+    pub old_path: String,
+    pub old_version: Option<String>,
+    pub new_path: String,
+    pub new_version: Option<String>,
+}
+
+impl fmt::Display for Replace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "replace {}", self.old_path)?;
+        if let Some(version) = &self.old_version {
+            write!(f, " {}", version)?;
+        }
+        write!(f, " => {}", self.new_path)?;
+        if let Some(version) = &self.new_version {
+            write!(f, " {}", version)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn parse(content: &str) -> Result<Vec<Directive>> {
+    let mut directives = Vec::new();
+    let mut block: Option<String> = None;
+    for (index, line) in content.lines().enumerate() {
+        let line_number = index + 1;
+        let (tokens, comment) =
+            tokenize(line).ok_or_else(|| anyhow::anyhow!("line {}: unterminated string", line_number))?;
+        if tokens.is_empty() {
+            continue;
+        }
+
+        let (verb, args) = match &block {
+            Some(_) if tokens == [")"] => {
+                block = None;
+                continue;
+            }
+            Some(verb) => (verb.clone(), tokens),
+            None if tokens.len() == 2 && tokens[1] == "(" => {
+                block = Some(tokens[0].clone());
+                continue;
+            }
+            None => (tokens[0].clone(), tokens[1..].to_vec()),
+        };
+        directives.push(Directive {
+            line: line_number,
+            verb,
+            args,
+            comment,
+        });
+    }
+
+    if let Some(verb) = block {
+        return Err(anyhow::anyhow!("unterminated {} block", verb));
+    }
+
+    Ok(directives)
+}
+
+pub fn parse_replace(args: &[String], line: usize) -> Result<Replace> {
+    let arrow = args
+        .iter()
+        .position(|arg| arg == "=>")
+        .ok_or_else(|| anyhow::anyhow!("line {}: replace directive is missing =>", line))?;
+    let (old, new) = (&args[..arrow], &args[arrow + 1..]);
+    match (old, new) {
+        ([old_path, old_version @ ..], [new_path, new_version @ ..])
+            if old_version.len() <= 1 && new_version.len() <= 1 =>
+        {
+            Ok(Replace {
+                old_path: old_path.clone(),
+                old_version: old_version.first().cloned(),
+                new_path: new_path.clone(),
+                new_version: new_version.first().cloned(),
+            })
+        }
+        _ => Err(anyhow::anyhow!("line {}: malformed replace directive", line)),
+    }
+}
+
+fn tokenize(line: &str) -> Option<(Vec<String>, String)> {
+    let mut tokens = Vec::new();
+    let mut chars = line.char_indices().peekable();
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if line[start..].starts_with("//") {
+            return Some((tokens, line[start + 2..].trim().to_string()));
+        } else if c == '"' || c == '`' {
+            chars.next();
+            let mut token = String::new();
+            let mut closed = false;
+            while let Some((_, next)) = chars.next() {
+                if next == '\\' && c == '"' {
+                    if let Some((_, escaped)) = chars.next() {
+                        token.push(escaped);
+                    }
+                } else if next == c {
+                    closed = true;
+                    break;
+                } else {
+                    token.push(next);
+                }
+            }
+            if !closed {
+                return None;
+            }
+            tokens.push(token);
+        } else if c == '(' || c == ')' {
+            chars.next();
+            tokens.push(c.to_string());
+        } else {
+            let mut end = line.len();
+            while let Some(&(index, next)) = chars.peek() {
+                if next.is_whitespace() || next == '(' || next == ')' || line[index..].starts_with("//") {
+                    end = index;
+                    break;
+                }
+                chars.next();
+            }
+            tokens.push(line[start..end].to_string());
+        }
+    }
+
+    Some((tokens, String::new()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(line: &str) -> (Vec<String>, String) {
+        tokenize(line).unwrap()
+    }
+
+    #[test]
+    fn tokenizes_quoted_strings_and_comments() {
+        assert_eq!(tokens("module \"example.com/a b\""), (vec!["module".into(), "example.com/a b".into()], String::new()));
+        assert_eq!(tokens("use `./x//y` // main"), (vec!["use".into(), "./x//y".into()], "main".into()));
+        assert_eq!(tokens("module \"a\\\"b\""), (vec!["module".into(), "a\"b".into()], String::new()));
+        assert_eq!(tokens("require(// deps"), (vec!["require".into(), "(".into()], "deps".into()));
+        assert_eq!(tokens("a v1//x"), (vec!["a".into(), "v1".into()], "x".into()));
+        assert!(tokenize("module \"example.com/m").is_none());
+    }
+
+    #[test]
+    fn expands_blocks_into_directives() {
+        let directives = parse("go 1.22\n\nuse (\n\t./a // first\n\n\t\"./b\"\n)\nuse ./c\n").unwrap();
+        let parsed: Vec<(usize, &str, Vec<&str>, &str)> = directives
+            .iter()
+            .map(|d| {
+                let args = d.args.iter().map(String::as_str).collect();
+                (d.line, d.verb.as_str(), args, d.comment.as_str())
+            })
+            .collect();
+        assert_eq!(
+            parsed,
+            [
+                (1, "go", vec!["1.22"], ""),
+                (4, "use", vec!["./a"], "first"),
+                (6, "use", vec!["./b"], ""),
+                (8, "use", vec!["./c"], ""),
+            ]
+        );
+        assert!(parse("use (\n\t./a\n").is_err());
+        assert!(parse("module \"m\n").is_err());
+    }
+
+    #[test]
+    fn parses_replace_forms() {
+        let args = |line: &str| tokens(line).0;
+        let replace = parse_replace(&args("a v1.0.0 => b v1.2.0"), 1).unwrap();
+        assert_eq!(replace.old_version.as_deref(), Some("v1.0.0"));
+        assert_eq!(replace.new_version.as_deref(), Some("v1.2.0"));
+        assert_eq!(replace.to_string(), "replace a v1.0.0 => b v1.2.0");
+
+        let replace = parse_replace(&args("a => ../a"), 1).unwrap();
+        assert_eq!((replace.old_version, replace.new_version), (None, None));
+
+        assert!(parse_replace(&args("a ../a"), 1).is_err());
+        assert!(parse_replace(&args("a => "), 1).is_err());
+        assert!(parse_replace(&args("a v1 v2 => b"), 1).is_err());
+    }
+}
@@ -0,0 +1,451 @@
+use anyhow::{Context, Result};
+use regex::{Captures, Regex};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tree_sitter::Node;
+
+use super::{GoVersion, FORMAT_VERSION};
+use crate::parser::{self, GoFile};
+use crate::project::signature_key;
+
+const SYMBOLS: &str = include_str!("symbols.txt");
+const HEADER: &str = "\
+# Go standard library exports used by dioxide's analyzers, limited to the names in symbols.txt.
+# Regenerate with `dioxide gen-stdlib --goroot <GOROOT> --output <this file>`.
+# Columns: name, Go version that introduced it, result types (`;`-separated, `-` for none),
+# deprecation note (`-` if not deprecated).
+";
+
+struct ApiSymbol {
+    package: String,
+    receiver: Option<String>,
+    name: String,
+    results: Vec<String>,
+}
+
+struct Entry {
+    name: String,
+    since: GoVersion,
+    results: Vec<String>,
+    deprecated: Option<String>,
+}
+
+pub fn generate(goroot: &Path, output: &Path) -> Result<()> {
+    let content = render(goroot)?;
+    fs::write(output, content).with_context(|| format!("Failed to write {}", output.display()))
+}
+
+fn render(goroot: &Path) -> Result<String> {
+    let go_version = goroot_version(goroot)?;
+    let mut symbols = Vec::new();
+    for (since, path) in api_files(goroot)? {
+        let content = fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+        symbols.extend(content.lines().filter_map(parse_api_line).map(|symbol| (since, symbol)));
+    }
+
+    let wanted: BTreeSet<String> = SYMBOLS
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(signature_key)
+        .collect();
+    let packages: BTreeSet<&str> = symbols.iter().map(|(_, symbol)| symbol.package.as_str()).collect();
+    let mut qualifiers: HashMap<&str, Vec<&str>> = HashMap::new();
+    for package in &packages {
+        qualifiers.entry(package_name(package)).or_default().push(package);
+    }
+    let mut deprecations = HashMap::new();
+    for package in packages.iter().filter(|package| {
+        let prefix = format!("{}.", package);
+        wanted.iter().any(|key| key.starts_with(&prefix))
+    }) {
+        deprecations.extend(scan_deprecations(goroot, package));
+    }
+
+    let identifier = Regex::new(r"\$?[A-Za-z_][A-Za-z0-9_]*(?:\.[A-Za-z_][A-Za-z0-9_]*)?")?;
+    let mut entries: BTreeMap<String, Entry> = BTreeMap::new();
+    for (since, symbol) in &symbols {
+        let name = match &symbol.receiver {
+            Some(receiver) => match receiver.strip_prefix('*') {
+                Some(receiver) => format!("(*{}.{}).{}", symbol.package, receiver, symbol.name),
+                None => format!("({}.{}).{}", symbol.package, receiver, symbol.name),
+            },
+            None => format!("{}.{}", symbol.package, symbol.name),
+        };
+        let key = signature_key(&name);
+        if !wanted.contains(&key) || entries.contains_key(&key) {
+            continue;
+        }
+        let results = symbol
+            .results
+            .iter()
+            .map(|result| qualify(&identifier, result, &symbol.package, &qualifiers))
+            .collect();
+        let entry = Entry {
+            name,
+            since: *since,
+            results,
+            deprecated: deprecations.get(&key).cloned(),
+        };
+        entries.insert(key, entry);
+    }
+
+    for key in wanted.iter().filter(|key| !entries.contains_key(*key)) {
+        log::warn!("{} is listed in symbols.txt but not found in {}", key, goroot.join("api").display());
+    }
+
+    let mut content = format!("{}format\t{}\ngo\t{}\n\n", HEADER, FORMAT_VERSION, go_version);
+    for entry in entries.values() {
+        let results = if entry.results.is_empty() {
+            "-".to_string()
+        } else {
+            entry.results.join(";")
+        };
+        let deprecated = entry.deprecated.as_deref().unwrap_or("-");
+        let _ = writeln!(content, "{}\t{}\t{}\t{}", entry.name, entry.since, results, deprecated);
+    }
+    Ok(content)
+}
+
+fn goroot_version(goroot: &Path) -> Result<GoVersion> {
+    let path = goroot.join("VERSION");
+    let content = fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let line = content.lines().next().unwrap_or_default();
+    line.find("go1")
+        .and_then(|start| GoVersion::parse(&line[start..]))
+        .with_context(|| format!("Unrecognized Go version in {}: {:?}", path.display(), line))
+}
+
+fn api_files(goroot: &Path) -> Result<Vec<(GoVersion, PathBuf)>> {
+    let dir = goroot.join("api");
+    let mut files = Vec::new();
+    for entry in fs::read_dir(&dir).with_context(|| format!("Failed to read {}", dir.display()))? {
+        let path = entry?.path();
+        let Some(stem) = path.file_name().and_then(|name| name.to_str()?.strip_suffix(".txt")) else {
+            continue;
+        };
+        if !stem.starts_with("go1") {
+            continue;
+        }
+        if let Some(version) = GoVersion::parse(stem) {
+            files.push((version, path));
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn parse_api_line(line: &str) -> Option<ApiSymbol> {
+    let rest = line.strip_prefix("pkg ")?;
+    let (package, declaration) = rest.split_once(", ")?;
+    let package = package.split(' ').next()?.to_string();
+    if package.split('/').any(|segment| segment == "internal" || segment == "vendor") {
+        return None;
+    }
+
+    let (receiver, signature) = if let Some(function) = declaration.strip_prefix("func ") {
+        (None, function)
+    } else if let Some(method) = declaration.strip_prefix("method (") {
+        let (receiver, signature) = method.split_once(") ")?;
+        (Some(strip_type_parameters(receiver)), signature)
+    } else if let Some(interface) = declaration.strip_prefix("type ") {
+        let (name, method) = interface.split_once(" interface, ")?;
+        (Some(strip_type_parameters(name)), method)
+    } else {
+        return None;
+    };
+
+    let end = signature.find(['[', '('])?;
+    let name = signature[..end].to_string();
+    let mut rest = &signature[end..];
+    if rest.starts_with('[') {
+        rest = &rest[balanced_end(rest)?..];
+    }
+    if !rest.starts_with('(') {
+        return None;
+    }
+    let results = rest[balanced_end(rest)?..].trim();
+    let results = match results.strip_prefix('(').and_then(|inner| inner.strip_suffix(')')) {
+        Some(inner) if balanced_end(results) == Some(results.len()) => split_top_level(inner),
+        _ if results.is_empty() => Vec::new(),
+        _ => vec![results.to_string()],
+    };
+
+    Some(ApiSymbol {
+        package,
+        receiver,
+        name,
+        results,
+    })
+}
+
+fn strip_type_parameters(name: &str) -> String {
+    name.split('[').next().unwrap_or(name).to_string()
+}
+
+fn balanced_end(text: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (index, c) in text.char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    return Some(index + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn split_top_level(text: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (index, c) in text.char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(text[start..index].trim().to_string());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(text[start..].trim().to_string());
+    parts.retain(|part| !part.is_empty());
+    parts
+}
+
+fn package_name(path: &str) -> &str {
+    let mut segments = path.rsplit('/');
+    let last = segments.next().unwrap_or(path);
+    let is_major_version = last.len() > 1 && last.starts_with('v') && last[1..].bytes().all(|b| b.is_ascii_digit());
+    match segments.next() {
+        Some(previous) if is_major_version => previous,
+        _ => last,
+    }
+}
+
+fn qualify(identifier: &Regex, type_text: &str, package: &str, qualifiers: &HashMap<&str, Vec<&str>>) -> String {
+    identifier
+        .replace_all(type_text, |captures: &Captures| {
+            let text = &captures[0];
+            if let Some((qualifier, name)) = text.split_once('.') {
+                return match resolve_qualifier(qualifier, package, qualifiers) {
+                    Some(path) => format!("{}.{}", path, name),
+                    None => text.to_string(),
+                };
+            }
+            if text.starts_with(|c: char| c.is_ascii_uppercase()) {
+                format!("{}.{}", package, text)
+            } else {
+                text.to_string()
+            }
+        })
+        .into_owned()
+}
+
+fn resolve_qualifier<'a>(qualifier: &str, package: &str, qualifiers: &HashMap<&str, Vec<&'a str>>) -> Option<&'a str> {
+    let candidates = qualifiers.get(qualifier)?;
+    candidates.iter().copied().max_by_key(|candidate| {
+        let shared = candidate.split('/').zip(package.split('/')).take_while(|(a, b)| a == b).count();
+        (shared, std::cmp::Reverse(candidate.len()))
+    })
+}
+
+fn scan_deprecations(goroot: &Path, package: &str) -> HashMap<String, String> {
+    let mut deprecations = HashMap::new();
+    let dir = goroot.join("src").join(package);
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return deprecations;
+    };
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension().is_some_and(|ext| ext == "go")
+                && !path.to_string_lossy().ends_with("_test.go")
+        })
+        .collect();
+    files.sort();
+
+    for path in files {
+        let ast = match parser::parse_file(&path) {
+            Ok(ast) => ast,
+            Err(e) => {
+                log::warn!("Skipping {}: {}", path.display(), e);
+                continue;
+            }
+        };
+        let root = ast.tree.root_node();
+        let mut cursor = root.walk();
+        for declaration in root.named_children(&mut cursor) {
+            let Some(name) = declared_name(&ast, package, declaration) else {
+                continue;
+            };
+            if let Some(note) = deprecation_note(&ast, declaration) {
+                deprecations.entry(signature_key(&name)).or_insert(note);
+            }
+        }
+    }
+    deprecations
+}
+
+fn declared_name(ast: &GoFile, package: &str, declaration: Node) -> Option<String> {
+    let name = declaration.child_by_field_name("name")?;
+    let name = ast.get_snippet(name.start_byte(), name.end_byte());
+    if !name.starts_with(|c: char| c.is_ascii_uppercase()) {
+        return None;
+    }
+    match declaration.kind() {
+        "function_declaration" => Some(format!("{}.{}", package, name)),
+        "method_declaration" => {
+            let receiver = declaration.child_by_field_name("receiver")?;
+            let mut cursor = receiver.walk();
+            let parameter = receiver.named_children(&mut cursor).next()?;
+            let mut type_node = parameter.child_by_field_name("type")?;
+            let pointer = type_node.kind() == "pointer_type";
+            if pointer {
+                type_node = type_node.named_child(0)?;
+            }
+            if type_node.kind() == "generic_type" {
+                type_node = type_node.child_by_field_name("type")?;
+            }
+            let receiver = ast.get_snippet(type_node.start_byte(), type_node.end_byte());
+            let pointer = if pointer { "*" } else { "" };
+            Some(format!("({}{}.{}).{}", pointer, package, receiver, name))
+        }
+        _ => None,
+    }
+}
+
+fn deprecation_note(ast: &GoFile, declaration: Node) -> Option<String> {
+    let mut lines = Vec::new();
+    let mut next_row = declaration.start_position().row;
+    let mut sibling = declaration.prev_sibling();
+    while let Some(comment) = sibling.filter(|node| node.kind() == "comment") {
+        if comment.end_position().row + 1 != next_row {
+            break;
+        }
+        let text = ast.get_snippet(comment.start_byte(), comment.end_byte());
+        lines.push(text.trim_start_matches("//").trim().to_string());
+        next_row = comment.start_position().row;
+        sibling = comment.prev_sibling();
+    }
+    lines.reverse();
+
+    let start = lines.iter().position(|line| line.starts_with("Deprecated:"))?;
+    let paragraph: Vec<&str> = lines[start..]
+        .iter()
+        .take_while(|line| !line.is_empty())
+        .flat_map(|line| line.split_whitespace())
+        .collect();
+    Some(paragraph.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    #[test]
+    fn parses_api_lines() {
+        let open = parse_api_line("pkg os, func Open(string) (*File, error)").unwrap();
+        assert_eq!((open.package.as_str(), open.receiver, open.name.as_str()), ("os", None, "Open"));
+        assert_eq!(open.results, ["*File", "error"]);
+
+        let close = parse_api_line("pkg syscall (linux-386), func Close(int) error").unwrap();
+        assert_eq!(close.package, "syscall");
+        assert_eq!(close.results, ["error"]);
+
+        let load = parse_api_line("pkg sync/atomic, method (*Pointer[$0]) Load() *$0").unwrap();
+        assert_eq!(load.receiver.as_deref(), Some("*Pointer"));
+        assert_eq!(load.results, ["*$0"]);
+
+        let index = parse_api_line("pkg slices, func Index[$0 interface{ ~[]$1 }, $1 comparable]($0, $1) int").unwrap();
+        assert_eq!(index.name, "Index");
+        assert_eq!(index.results, ["int"]);
+
+        let write = parse_api_line("pkg io, type Writer interface, Write([]uint8) (int, error)").unwrap();
+        assert_eq!(write.receiver.as_deref(), Some("Writer"));
+
+        let callback = parse_api_line("pkg os, func F(func(int, error) bool) (func() (int, error), map[string]File)").unwrap();
+        assert_eq!(callback.results, ["func() (int, error)", "map[string]File"]);
+
+        assert!(parse_api_line("pkg os, func Exit(int)").unwrap().results.is_empty());
+        assert!(parse_api_line("pkg io, type Writer interface { Write }").is_none());
+        assert!(parse_api_line("pkg os, const O_RDONLY int").is_none());
+        assert!(parse_api_line("pkg internal/poll, func Open() error").is_none());
+    }
+
+    #[test]
+    fn qualifies_result_types() {
+        let identifier = Regex::new(r"\$?[A-Za-z_][A-Za-z0-9_]*(?:\.[A-Za-z_][A-Za-z0-9_]*)?").unwrap();
+        let mut qualifiers: HashMap<&str, Vec<&str>> = HashMap::new();
+        for package in ["io/fs", "math/rand", "crypto/rand", "math/rand/v2", "os"] {
+            qualifiers.entry(package_name(package)).or_default().push(package);
+        }
+        assert_eq!(qualify(&identifier, "*File", "os", &qualifiers), "*os.File");
+        assert_eq!(qualify(&identifier, "[]fs.DirEntry", "os", &qualifiers), "[]io/fs.DirEntry");
+        assert_eq!(qualify(&identifier, "map[string]int", "os", &qualifiers), "map[string]int");
+        assert_eq!(qualify(&identifier, "rand.Reader", "crypto/ecdh", &qualifiers), "crypto/rand.Reader");
+        assert_eq!(qualify(&identifier, "*rand.Rand", "math/big", &qualifiers), "*math/rand.Rand");
+        assert_eq!(qualify(&identifier, "*$0", "sync/atomic", &qualifiers), "*$0");
+    }
+
+    #[test]
+    fn renders_listed_symbols_from_goroot() {
+        let goroot = TempDir::new("goroot");
+        goroot.write("VERSION", "go1.22.3\ntime 2024-05-01\n");
+        goroot.write(
+            "api/go1.txt",
+            "pkg io, type Writer interface { Write }\n\
+             pkg io, type Writer interface, Write([]uint8) (int, error)\n\
+             pkg io/ioutil, func ReadAll(io.Reader) ([]uint8, error)\n\
+             pkg os, func Open(string) (*File, error)\n\
+             pkg os, method (*File) Close() error\n\
+             pkg os, method (*File) Stat() (FileInfo, error)\n\
+             pkg os, func NotListed() int\n",
+        );
+        goroot.write(
+            "api/go1.16.txt",
+            "pkg os, func ReadFile(string) ([]uint8, error)\npkg os, func Open(string) (*File, error)\n",
+        );
+        goroot.write("api/next/12345.txt", "pkg os, func Getenv(string) string\n");
+        goroot.write(
+            "src/io/ioutil/ioutil.go",
+            "package ioutil\n\n\
+             // ReadAll reads from r until an error or EOF.\n\
+             //\n\
+             // Deprecated: As of Go 1.16, this function\n\
+             // simply calls io.ReadAll.\n\
+             //\n\
+             // More text.\n\
+             func ReadAll(r io.Reader) ([]byte, error) {\n\treturn io.ReadAll(r)\n}\n",
+        );
+
+        let content = render(goroot.path()).unwrap();
+
+        let rows: Vec<&str> = content.lines().filter(|line| !line.starts_with('#')).collect();
+        assert_eq!(
+            rows,
+            [
+                "format\t1",
+                "go\t1.22",
+                "",
+                "(io.Writer).Write\t1.0\tint;error\t-",
+                "io/ioutil.ReadAll\t1.0\t[]uint8;error\tDeprecated: As of Go 1.16, this function simply calls io.ReadAll.",
+                "(*os.File).Close\t1.0\terror\t-",
+                "(*os.File).Stat\t1.0\tos.FileInfo;error\t-",
+                "os.Open\t1.0\t*os.File;error\t-",
+                "os.ReadFile\t1.16\t[]uint8;error\t-",
+            ]
+        );
+        assert!(super::super::Stdlib::parse(&content).is_ok());
+    }
+}
@@ -0,0 +1,192 @@
+use anyhow::{anyhow, bail, Context, Result};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::OnceLock;
+
+mod generate;
+
+pub use generate::generate;
+
+const FORMAT_VERSION: u32 = 1;
+const BUNDLED: &str = include_str!("stdlib.tsv");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GoVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl GoVersion {
+    pub fn parse(version: &str) -> Option<GoVersion> {
+        let version = version.trim();
+        let version = version.strip_prefix("go").unwrap_or(version);
+        let mut parts = version.split('.').map(|part| {
+            let digits = part.bytes().take_while(u8::is_ascii_digit).count();
+            part[..digits].parse::<u32>()
+        });
+        let major = parts.next()?.ok()?;
+        let minor = parts.next().unwrap_or(Ok(0)).ok()?;
+        Some(GoVersion { major, minor })
+    }
+}
+
+impl fmt::Display for GoVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StdlibSymbol {
+    pub name: String,
+    pub since: GoVersion,
+    pub results: Vec<String>,
+    pub deprecated: Option<String>,
+}
+
+impl StdlibSymbol {
+    pub fn error_position(&self) -> Option<usize> {
+        self.results.iter().rposition(|result| result == "error")
+    }
+}
+
+#[derive(Debug)]
+pub struct Stdlib {
+    pub go_version: GoVersion,
+    symbols: HashMap<String, StdlibSymbol>,
+}
+
+impl Stdlib {
+    pub fn parse(content: &str) -> Result<Stdlib> {
+        let mut format = None;
+        let mut go_version = None;
+        let mut symbols = HashMap::new();
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim_end();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split('\t').collect();
+            let parsed = match fields.as_slice() {
+                ["format", value] => value
+                    .parse()
+                    .map(|value| format = Some(value))
+                    .map_err(|_| anyhow!("invalid format version {:?}", value)),
+                ["go", value] => GoVersion::parse(value)
+                    .map(|value| go_version = Some(value))
+                    .ok_or_else(|| anyhow!("invalid Go version {:?}", value)),
+                [name, since, results, deprecated] => parse_symbol(name, since, results, deprecated).map(|symbol| {
+                    symbols.insert(super::signature_key(name), symbol);
+                }),
+                _ => Err(anyhow!("expected 4 tab-separated columns")),
+            };
+            parsed.with_context(|| format!("line {}", index + 1))?;
+        }
+
+        match format {
+            Some(FORMAT_VERSION) => {}
+            Some(other) => bail!("unsupported stdlib table format {}", other),
+            None => bail!("missing format header"),
+        }
+        let go_version = go_version.context("missing go header")?;
+        Ok(Stdlib { go_version, symbols })
+    }
+
+    pub fn lookup(&self, key: &str, go_version: Option<GoVersion>) -> Option<&StdlibSymbol> {
+        self.symbols
+            .get(key)
+            .filter(|symbol| go_version.is_none_or(|version| symbol.since <= version))
+    }
+
+    pub fn symbols(&self) -> impl Iterator<Item = &StdlibSymbol> {
+        self.symbols.values()
+    }
+}
+
+pub fn stdlib() -> &'static Stdlib {
+    static STDLIB: OnceLock<Stdlib> = OnceLock::new();
+    STDLIB.get_or_init(|| Stdlib::parse(BUNDLED).expect("bundled standard library table is malformed"))
+}
+
+fn parse_symbol(name: &str, since: &str, results: &str, deprecated: &str) -> Result<StdlibSymbol> {
+    let since = GoVersion::parse(since).ok_or_else(|| anyhow!("invalid Go version {:?}", since))?;
+    let results = match results {
+        "-" => Vec::new(),
+        results => results.split(';').map(str::to_string).collect(),
+    };
+    let deprecated = (deprecated != "-").then(|| deprecated.to_string());
+    Ok(StdlibSymbol {
+        name: name.to_string(),
+        since,
+        results,
+        deprecated,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn parses_go_versions() {
+        assert_eq!(GoVersion::parse("1.21"), Some(GoVersion { major: 1, minor: 21 }));
+        assert_eq!(GoVersion::parse("1.21.3"), Some(GoVersion { major: 1, minor: 21 }));
+        assert_eq!(GoVersion::parse("go1.22rc1"), Some(GoVersion { major: 1, minor: 22 }));
+        assert_eq!(GoVersion::parse("go1"), Some(GoVersion { major: 1, minor: 0 }));
+        assert_eq!(GoVersion::parse("devel"), None);
+    }
+
+    #[test]
+    fn parses_bundled_table() {
+        let stdlib = Stdlib::parse(BUNDLED).unwrap();
+        assert_eq!(stdlib.go_version, GoVersion { major: 1, minor: 22 });
+
+        let open = stdlib.lookup("os.Open", None).unwrap();
+        assert_eq!(open.name, "os.Open");
+        assert_eq!(open.results, ["*os.File", "error"]);
+        assert_eq!(open.error_position(), Some(1));
+
+        let close = stdlib.lookup("os.File.Close", None).unwrap();
+        assert_eq!(close.name, "(*os.File).Close");
+        assert_eq!(close.error_position(), Some(0));
+
+        let index = stdlib.lookup("strings.Index", None).unwrap();
+        assert_eq!(index.results, ["int"]);
+        assert_eq!(index.error_position(), None);
+        assert!(stdlib.lookup("os.Getenv", None).is_some());
+        assert!(stdlib.lookup("bufio.NewWriter", None).is_some());
+        assert!(stdlib.lookup("os.Exit", None).unwrap().results.is_empty());
+
+        assert!(stdlib.lookup("io/ioutil.ReadFile", None).unwrap().deprecated.is_some());
+        assert!(stdlib.lookup("os.ReadFile", None).unwrap().deprecated.is_none());
+    }
+
+    #[test]
+    fn bundled_table_matches_symbol_list() {
+        let listed: BTreeSet<String> = include_str!("symbols.txt")
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(str::to_string)
+            .collect();
+        let bundled: BTreeSet<String> = stdlib().symbols().map(|symbol| symbol.name.clone()).collect();
+        assert_eq!(listed, bundled);
+    }
+
+    #[test]
+    fn lookup_respects_go_version() {
+        let stdlib = stdlib();
+        assert!(stdlib.lookup("os.ReadFile", GoVersion::parse("1.15")).is_none());
+        assert!(stdlib.lookup("os.ReadFile", GoVersion::parse("1.16")).is_some());
+        assert!(stdlib.lookup("os.Open", GoVersion::parse("1.0")).is_some());
+    }
+
+    #[test]
+    fn rejects_malformed_tables() {
+        assert!(Stdlib::parse("go\t1.22\n").is_err());
+        assert!(Stdlib::parse("format\t2\ngo\t1.22\n").is_err());
+        assert!(Stdlib::parse("format\t1\n").is_err());
+        assert!(Stdlib::parse("format\t1\ngo\t1.22\nos.Open\t1.0\terror\n").is_err());
+        assert!(Stdlib::parse("format\t1\ngo\t1.22\nos.Open\tnext\terror\t-\n").is_err());
+    }
+}
@@ -0,0 +1,313 @@
+use std::collections::HashSet;
+use tree_sitter::Node;
+
+use crate::parser::{named_children, GoFile};
+
+const DECLARING_KINDS: &[&str] = &[
+    "function_declaration",
+    "parameter_declaration",
+    "variadic_parameter_declaration",
+    "const_spec",
+    "var_spec",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeclarationKind {
+    Function,
+    Method,
+    Type,
+    Const,
+    Var,
+}
+
+impl DeclarationKind {
+    pub fn describe(self) -> &'static str {
+        match self {
+            DeclarationKind::Function => "function",
+            DeclarationKind::Method => "method",
+            DeclarationKind::Type => "type",
+            DeclarationKind::Const => "constant",
+            DeclarationKind::Var => "variable",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Declaration {
+    pub name: String,
+    pub receiver: Option<String>,
+    pub pointer_receiver: bool,
+    pub kind: DeclarationKind,
+    pub error_result: Option<usize>,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Declaration {
+    pub fn qualified_name(&self) -> String {
+        match &self.receiver {
+            Some(receiver) => format!("{}.{}", receiver, self.name),
+            None => self.name.clone(),
+        }
+    }
+
+    pub fn is_exported(&self) -> bool {
+        self.name.chars().next().is_some_and(char::is_uppercase)
+    }
+}
+
+pub fn declarations(ast: &GoFile) -> Vec<Declaration> {
+    let mut declarations = Vec::new();
+    let root = ast.tree.root_node();
+    let mut cursor = root.walk();
+    for node in root.named_children(&mut cursor) {
+        match node.kind() {
+            "function_declaration" => {
+                if let Some(name) = node.child_by_field_name("name") {
+                    let mut declaration = declaration(ast, name, None, DeclarationKind::Function);
+                    declaration.error_result = error_result(ast, node);
+                    declarations.push(declaration);
+                }
+            }
+            "method_declaration" => {
+                if let Some(name) = node.child_by_field_name("name") {
+                    let receiver = node.child_by_field_name("receiver");
+                    let mut declaration = declaration(
+                        ast,
+                        name,
+                        receiver.and_then(|r| receiver_type(ast, r)),
+                        DeclarationKind::Method,
+                    );
+                    declaration.pointer_receiver = receiver.is_some_and(is_pointer_receiver);
+                    declaration.error_result = error_result(ast, node);
+                    declarations.push(declaration);
+                }
+            }
+            "type_declaration" => {
+                let mut specs = node.walk();
+                for spec in node.named_children(&mut specs) {
+                    if let Some(name) = spec.child_by_field_name("name") {
+                        declarations.push(declaration(ast, name, None, DeclarationKind::Type));
+                    }
+                }
+            }
+            "const_declaration" | "var_declaration" => {
+                let kind = if node.kind() == "const_declaration" {
+                    DeclarationKind::Const
+                } else {
+                    DeclarationKind::Var
+                };
+                for spec in specs(node) {
+                    for name in spec_names(spec) {
+                        declarations.push(declaration(ast, name, None, kind));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    declarations
+}
+
+fn declaration(ast: &GoFile, name: Node, receiver: Option<String>, kind: DeclarationKind) -> Declaration {
+    let (line, column) = ast.get_position(name.start_byte());
+    Declaration {
+        name: ast.get_snippet(name.start_byte(), name.end_byte()),
+        receiver,
+        pointer_receiver: false,
+        kind,
+        error_result: None,
+        line,
+        column,
+    }
+}
+
+pub fn specs(declaration: Node) -> Vec<Node> {
+    let mut cursor = declaration.walk();
+    let specs: Vec<Node> = declaration
+        .named_children(&mut cursor)
+        .filter(|child| matches!(child.kind(), "const_spec" | "var_spec"))
+        .collect();
+    specs
+}
+
+pub fn spec_names(spec: Node) -> Vec<Node> {
+    let mut cursor = spec.walk();
+    let names: Vec<Node> = spec.children_by_field_name("name", &mut cursor).collect();
+    names
+}
+
+pub fn receiver_type(ast: &GoFile, receiver: Node) -> Option<String> {
+    let mut cursor = receiver.walk();
+    let parameter = receiver.named_children(&mut cursor).next()?;
+    let type_node = parameter.child_by_field_name("type")?;
+    first_type_identifier(type_node).map(|t| ast.get_snippet(t.start_byte(), t.end_byte()))
+}
+
+fn is_pointer_receiver(receiver: Node) -> bool {
+    let mut cursor = receiver.walk();
+    let parameter = receiver.named_children(&mut cursor).next();
+    parameter
+        .and_then(|p| p.child_by_field_name("type"))
+        .is_some_and(|t| t.kind() == "pointer_type")
+}
+
+fn error_result(ast: &GoFile, function: Node) -> Option<usize> {
+    let result = function.child_by_field_name("result")?;
+    if result.kind() != "parameter_list" {
+        return (ast.get_snippet(result.start_byte(), result.end_byte()) == "error").then_some(0);
+    }
+
+    let mut types = Vec::new();
+    let mut cursor = result.walk();
+    for parameter in result.named_children(&mut cursor) {
+        let Some(type_node) = parameter.child_by_field_name("type") else {
+            continue;
+        };
+        let mut names = parameter.walk();
+        let count = parameter.children_by_field_name("name", &mut names).count().max(1);
+        let type_name = ast.get_snippet(type_node.start_byte(), type_node.end_byte());
+        types.extend(std::iter::repeat_n(type_name, count));
+    }
+    types.iter().rposition(|t| t == "error")
+}
+
+fn first_type_identifier(node: Node) -> Option<Node> {
+    if node.kind() == "type_identifier" {
+        return Some(node);
+    }
+    named_children(node).into_iter().find_map(first_type_identifier)
+}
+
+pub fn references(ast: &GoFile) -> HashSet<String> {
+    let mut references = HashSet::new();
+    let mut nodes = ast.find_nodes("identifier");
+    nodes.extend(ast.find_nodes("type_identifier"));
+    for node in nodes {
+        let name = ast.get_snippet(node.start_byte(), node.end_byte());
+        if !declares(ast, node, &name) {
+            references.insert(name);
+        }
+    }
+
+    references
+}
+
+fn declares(ast: &GoFile, node: Node, name: &str) -> bool {
+    let mut ancestor = node.parent();
+    while let Some(parent) = ancestor {
+        match parent.kind() {
+            "function_declaration" | "type_spec" | "type_alias" => {
+                return parent
+                    .child_by_field_name("name")
+                    .is_some_and(|n| ast.get_snippet(n.start_byte(), n.end_byte()) == name);
+            }
+            "const_spec" | "var_spec" => {
+                return spec_names(parent)
+                    .iter()
+                    .any(|n| ast.get_snippet(n.start_byte(), n.end_byte()) == name);
+            }
+            "method_declaration" => return false,
+            _ => ancestor = parent.parent(),
+        }
+    }
+    false
+}
+
+pub fn qualified_references(ast: &GoFile) -> HashSet<(String, String)> {
+    let mut references = HashSet::new();
+    for node in ast.find_nodes("selector_expression") {
+        let operand = node.child_by_field_name("operand");
+        let field = node.child_by_field_name("field");
+        if let (Some(operand), Some(field)) = (operand, field) {
+            if operand.kind() == "identifier" {
+                references.insert((
+                    ast.get_snippet(operand.start_byte(), operand.end_byte()),
+                    ast.get_snippet(field.start_byte(), field.end_byte()),
+                ));
+            }
+        }
+    }
+    for node in ast.find_nodes("qualified_type") {
+        let package = node.child_by_field_name("package");
+        let name = node.child_by_field_name("name");
+        if let (Some(package), Some(name)) = (package, name) {
+            references.insert((
+                ast.get_snippet(package.start_byte(), package.end_byte()),
+                ast.get_snippet(name.start_byte(), name.end_byte()),
+            ));
+        }
+    }
+
+    references
+}
+
+pub fn member_references(ast: &GoFile) -> HashSet<String> {
+    let mut members = HashSet::new();
+    for node in ast.find_nodes("selector_expression") {
+        if let Some(field) = node.child_by_field_name("field") {
+            members.insert(ast.get_snippet(field.start_byte(), field.end_byte()));
+        }
+    }
+
+    members
+}
+
+pub fn interface_methods(ast: &GoFile) -> HashSet<String> {
+    let mut methods = HashSet::new();
+    for node in ast.find_nodes("method_spec") {
+        if let Some(name) = node.child_by_field_name("name") {
+            methods.insert(ast.get_snippet(name.start_byte(), name.end_byte()));
+        }
+    }
+
+    methods
+}
+
+pub fn value_references(ast: &GoFile) -> HashSet<String> {
+    let mut values = HashSet::new();
+    let mut nodes = ast.find_nodes("identifier");
+    nodes.extend(ast.find_nodes("field_identifier"));
+    for node in nodes {
+        let Some(parent) = node.parent() else {
+            continue;
+        };
+        let referenced = match (node.kind(), parent.kind()) {
+            ("field_identifier", "selector_expression") => true,
+            ("identifier", _) => !DECLARING_KINDS.contains(&parent.kind()),
+            _ => false,
+        };
+        if !referenced {
+            continue;
+        }
+        let callee = if node.kind() == "field_identifier" { parent } else { node };
+        let is_call = callee
+            .parent()
+            .is_some_and(|p| p.kind() == "call_expression" && p.child_by_field_name("function") == Some(callee));
+        if !is_call {
+            values.insert(ast.get_snippet(node.start_byte(), node.end_byte()));
+        }
+    }
+
+    values
+}
+
+pub fn unkeyed_literals(ast: &GoFile) -> HashSet<String> {
+    let mut types = HashSet::new();
+    for node in ast.find_nodes("composite_literal") {
+        let type_node = node.child_by_field_name("type");
+        let body = node.child_by_field_name("body");
+        if let (Some(type_node), Some(body)) = (type_node, body) {
+            let mut cursor = body.walk();
+            let unkeyed = body.named_children(&mut cursor).any(|element| element.kind() == "literal_element");
+            if unkeyed {
+                if let Some(name) = first_type_identifier(type_node) {
+                    types.insert(ast.get_snippet(name.start_byte(), name.end_byte()));
+                }
+            }
+        }
+    }
+
+    types
+}
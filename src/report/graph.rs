@@ -0,0 +1,267 @@
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use crate::project::{ImportGraph, Project};
+
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum GraphFormat {
+    Dot,
+    Mermaid,
+    Json,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct GraphOptions {
+    pub depth: Option<usize>,
+    pub filter: Option<String>,
+}
+
+struct Rendered {
+    graph: ImportGraph,
+    component: HashMap<String, usize>,
+    cycles: Vec<Vec<String>>,
+}
+
+impl Rendered {
+    fn in_cycle(&self, node: &str) -> bool {
+        self.component.contains_key(node)
+    }
+
+    fn is_cycle_edge(&self, from: &str, to: &str) -> bool {
+        matches!((self.component.get(from), self.component.get(to)), (Some(a), Some(b)) if a == b)
+    }
+}
+
+#[derive(Serialize)]
+struct JsonGraph<'a> {
+    version: u32,
+    nodes: Vec<JsonNode<'a>>,
+    edges: Vec<JsonEdge<'a>>,
+    cycles: &'a [Vec<String>],
+}
+
+#[derive(Serialize)]
+struct JsonNode<'a> {
+    id: &'a str,
+    in_cycle: bool,
+}
+
+#[derive(Serialize)]
+struct JsonEdge<'a> {
+    from: &'a str,
+    to: &'a str,
+    in_cycle: bool,
+}
+
+pub fn render(project: &Project, format: GraphFormat, options: &GraphOptions) -> Result<String> {
+    let rendered = prepare(project, options);
+    match format {
+        GraphFormat::Dot => Ok(render_dot(&rendered)),
+        GraphFormat::Mermaid => Ok(render_mermaid(&rendered)),
+        GraphFormat::Json => render_json(&rendered),
+    }
+}
+
+pub fn print(project: &Project, format: GraphFormat, options: &GraphOptions) -> Result<()> {
+    print!("{}", render(project, format, options)?);
+    Ok(())
+}
+
+fn prepare(project: &Project, options: &GraphOptions) -> Rendered {
+    let mut graph = project.import_graph();
+    if let Some(filter) = &options.filter {
+        let filter = filter.trim_end_matches('/');
+        graph = graph.retain_nodes(|node| {
+            let relative = project.packages.get(node).map_or(node, |package| package.relative_path());
+            [node, relative]
+                .iter()
+                .any(|path| *path == filter || path.strip_prefix(filter).is_some_and(|rest| rest.starts_with('/')))
+        });
+    }
+    if let Some(depth) = options.depth {
+        graph = graph.map_nodes(|node| project.collapsed_path(node, depth));
+    }
+
+    let mut component = HashMap::new();
+    for (index, members) in graph.cyclic_components().into_iter().enumerate() {
+        for member in members {
+            component.insert(member, index);
+        }
+    }
+    let cycles = graph.cycles();
+
+    Rendered {
+        graph,
+        component,
+        cycles,
+    }
+}
+
+fn render_dot(rendered: &Rendered) -> String {
+    let mut out = String::new();
+    out.push_str("digraph dependencies {\n");
+    out.push_str("    rankdir=LR;\n");
+    out.push_str("    node [shape=box, fontname=\"Helvetica\"];\n");
+    for node in rendered.graph.nodes() {
+        if rendered.in_cycle(node) {
+            let _ = writeln!(out, "    {} [color=red, fontcolor=red];", quote_dot(node));
+        } else {
+            let _ = writeln!(out, "    {};", quote_dot(node));
+        }
+    }
+    for (from, to) in rendered.graph.edges() {
+        if rendered.is_cycle_edge(from, to) {
+            let _ = writeln!(out, "    {} -> {} [color=red, penwidth=2];", quote_dot(from), quote_dot(to));
+        } else {
+            let _ = writeln!(out, "    {} -> {};", quote_dot(from), quote_dot(to));
+        }
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn quote_dot(id: &str) -> String {
+    format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn render_mermaid(rendered: &Rendered) -> String {
+    let ids: HashMap<&str, String> = rendered
+        .graph
+        .nodes()
+        .enumerate()
+        .map(|(index, node)| (node.as_str(), format!("n{}", index)))
+        .collect();
+
+    let mut out = String::new();
+    out.push_str("graph LR\n");
+    for node in rendered.graph.nodes() {
+        let _ = writeln!(out, "    {}[\"{}\"]", ids[node.as_str()], node.replace('"', "#quot;"));
+    }
+
+    let mut cycle_links = Vec::new();
+    for (index, (from, to)) in rendered.graph.edges().enumerate() {
+        let _ = writeln!(out, "    {} --> {}", ids[from.as_str()], ids[to.as_str()]);
+        if rendered.is_cycle_edge(from, to) {
+            cycle_links.push(index.to_string());
+        }
+    }
+
+    let cycle_nodes: Vec<&str> = rendered
+        .graph
+        .nodes()
+        .filter(|node| rendered.in_cycle(node))
+        .map(|node| ids[node.as_str()].as_str())
+        .collect();
+    if !cycle_nodes.is_empty() {
+        out.push_str("    classDef cycle stroke:#d00,stroke-width:2px,color:#d00;\n");
+        let _ = writeln!(out, "    class {} cycle;", cycle_nodes.join(","));
+    }
+    if !cycle_links.is_empty() {
+        let _ = writeln!(out, "    linkStyle {} stroke:#d00,stroke-width:2px;", cycle_links.join(","));
+    }
+    out
+}
+
+fn render_json(rendered: &Rendered) -> Result<String> {
+    let graph = JsonGraph {
+        version: SCHEMA_VERSION,
+        nodes: rendered
+            .graph
+            .nodes()
+            .map(|node| JsonNode {
+                id: node,
+                in_cycle: rendered.in_cycle(node),
+            })
+            .collect(),
+        edges: rendered
+            .graph
+            .edges()
+            .map(|(from, to)| JsonEdge {
+                from,
+                to,
+                in_cycle: rendered.is_cycle_edge(from, to),
+            })
+            .collect(),
+        cycles: &rendered.cycles,
+    };
+
+    let mut out = serde_json::to_string_pretty(&graph).context("Failed to serialize dependency graph as JSON")?;
+    out.push('\n');
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer;
+    use crate::config::Config;
+    use crate::test_support::TempDir;
+
+    fn project() -> Project {
+        let dir = TempDir::new("graph");
+        dir.write("go.mod", "module example.com/m\n");
+        dir.write("cmd/main.go", "package main\n\nimport _ \"example.com/m/api\"\n");
+        dir.write("api/api.go", "package api\n\nimport _ \"example.com/m/store\"\n");
+        dir.write("store/store.go", "package store\n\nimport _ \"example.com/m/store/cache\"\n");
+        dir.write("store/cache/cache.go", "package cache\n\nimport _ \"example.com/m/store\"\n");
+        analyzer::build_project(dir.path(), &Config::default(), None).unwrap()
+    }
+
+    #[test]
+    fn dot_highlights_cycles() {
+        let dot = render(&project(), GraphFormat::Dot, &GraphOptions::default()).unwrap();
+        assert!(dot.starts_with("digraph dependencies {\n"));
+        assert!(dot.contains("    \"example.com/m/api\";\n"));
+        assert!(dot.contains("    \"example.com/m/store\" [color=red, fontcolor=red];\n"));
+        assert!(dot.contains("    \"example.com/m/cmd\" -> \"example.com/m/api\";\n"));
+        assert!(dot.contains("    \"example.com/m/store\" -> \"example.com/m/store/cache\" [color=red, penwidth=2];\n"));
+        assert!(dot.contains("    \"example.com/m/api\" -> \"example.com/m/store\";\n"));
+    }
+
+    #[test]
+    fn mermaid_styles_cycle_nodes_and_links() {
+        let mermaid = render(&project(), GraphFormat::Mermaid, &GraphOptions::default()).unwrap();
+        let expected = "graph LR\n    n0[\"example.com/m/api\"]\n    n1[\"example.com/m/cmd\"]\n    n2[\"example.com/m/store\"]\n    n3[\"example.com/m/store/cache\"]\n    n0 --> n2\n    n1 --> n0\n    n2 --> n3\n    n3 --> n2\n    classDef cycle stroke:#d00,stroke-width:2px,color:#d00;\n    class n2,n3 cycle;\n    linkStyle 2,3 stroke:#d00,stroke-width:2px;\n";
+        assert_eq!(mermaid, expected);
+    }
+
+    #[test]
+    fn json_lists_nodes_edges_and_cycles() {
+        let json = render(&project(), GraphFormat::Json, &GraphOptions::default()).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["version"], SCHEMA_VERSION);
+        assert_eq!(value["nodes"].as_array().unwrap().len(), 4);
+        assert_eq!(value["nodes"][0]["in_cycle"], false);
+        assert_eq!(value["edges"][2]["in_cycle"], true);
+        assert_eq!(
+            value["cycles"],
+            serde_json::json!([["example.com/m/store", "example.com/m/store/cache", "example.com/m/store"]])
+        );
+    }
+
+    #[test]
+    fn filters_and_collapses_packages() {
+        let project = project();
+        let options = GraphOptions {
+            depth: None,
+            filter: Some("store/".to_string()),
+        };
+        let rendered = prepare(&project, &options);
+        let nodes: Vec<&String> = rendered.graph.nodes().collect();
+        assert_eq!(nodes, ["example.com/m/store", "example.com/m/store/cache"]);
+
+        let options = GraphOptions {
+            depth: Some(1),
+            filter: None,
+        };
+        let rendered = prepare(&project, &options);
+        let nodes: Vec<&String> = rendered.graph.nodes().collect();
+        assert_eq!(nodes, ["example.com/m/api", "example.com/m/cmd", "example.com/m/store"]);
+        assert!(rendered.cycles.is_empty());
+    }
+}
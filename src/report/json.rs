@@ -0,0 +1,59 @@
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::analyzer::{Issue, Severity};
+
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct Report<'a> {
+    version: u32,
+    tool: Tool,
+    summary: Summary,
+    issues: &'a [Issue],
+}
+
+#[derive(Serialize)]
+struct Tool {
+    name: &'static str,
+    version: &'static str,
+}
+
+#[derive(Serialize)]
+struct Summary {
+    total: usize,
+    errors: usize,
+    warnings: usize,
+    info: usize,
+}
+
+impl Summary {
+    fn from_issues(issues: &[Issue]) -> Self {
+        let count = |severity: Severity| issues.iter().filter(|i| i.severity == severity).count();
+        Summary {
+            total: issues.len(),
+            errors: count(Severity::Error),
+            warnings: count(Severity::Warning),
+            info: count(Severity::Info),
+        }
+    }
+}
+
+pub fn render(issues: &[Issue]) -> Result<String> {
+    let report = Report {
+        version: SCHEMA_VERSION,
+        tool: Tool {
+            name: env!("CARGO_PKG_NAME"),
+            version: env!("CARGO_PKG_VERSION"),
+        },
+        summary: Summary::from_issues(issues),
+        issues,
+    };
+
+    serde_json::to_string_pretty(&report).context("Failed to serialize issues as JSON")
+}
+
+pub fn print(issues: &[Issue]) -> Result<()> {
+    println!("{}", render(issues)?);
+    Ok(())
+}
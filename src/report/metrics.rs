@@ -0,0 +1,106 @@
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::fmt::Write;
+
+use crate::project::metrics::PackageMetrics;
+
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum MetricsFormat {
+    Text,
+    Json,
+}
+
+#[derive(Serialize)]
+struct Report<'a> {
+    version: u32,
+    packages: &'a [PackageMetrics],
+}
+
+pub fn render(metrics: &[PackageMetrics], format: MetricsFormat) -> Result<String> {
+    match format {
+        MetricsFormat::Text => Ok(render_text(metrics)),
+        MetricsFormat::Json => {
+            let report = Report {
+                version: SCHEMA_VERSION,
+                packages: metrics,
+            };
+            let mut out =
+                serde_json::to_string_pretty(&report).context("Failed to serialize package metrics as JSON")?;
+            out.push('\n');
+            Ok(out)
+        }
+    }
+}
+
+pub fn print(metrics: &[PackageMetrics], format: MetricsFormat) -> Result<()> {
+    print!("{}", render(metrics, format)?);
+    Ok(())
+}
+
+fn render_text(metrics: &[PackageMetrics]) -> String {
+    let width = metrics
+        .iter()
+        .map(|m| m.package.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("PACKAGE".len());
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "{:<width$}  {:>4}  {:>4}  {:>5}  {:>5}  {:>5}",
+        "PACKAGE", "CA", "CE", "I", "A", "D",
+        width = width
+    );
+    for m in metrics {
+        let _ = writeln!(
+            out,
+            "{:<width$}  {:>4}  {:>4}  {:>5.2}  {:>5.2}  {:>5.2}",
+            m.package, m.afferent, m.efferent, m.instability, m.abstractness, m.distance,
+            width = width
+        );
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metrics() -> Vec<PackageMetrics> {
+        vec![PackageMetrics {
+            package: "example.com/m/core".to_string(),
+            afferent: 2,
+            efferent: 1,
+            instability: 1.0 / 3.0,
+            interfaces: 1,
+            concrete_types: 1,
+            abstractness: 0.5,
+            distance: 1.0 / 6.0,
+        }]
+    }
+
+    #[test]
+    fn renders_an_aligned_table() {
+        let text = render(&metrics(), MetricsFormat::Text).unwrap();
+        assert_eq!(
+            text,
+            "PACKAGE               CA    CE      I      A      D\n\
+             example.com/m/core     2     1   0.33   0.50   0.17\n"
+        );
+        assert_eq!(render(&[], MetricsFormat::Text).unwrap(), "PACKAGE    CA    CE      I      A      D\n");
+    }
+
+    #[test]
+    fn renders_versioned_json() {
+        let json = render(&metrics(), MetricsFormat::Json).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["version"], SCHEMA_VERSION);
+        assert_eq!(value["packages"][0]["package"], "example.com/m/core");
+        assert_eq!(value["packages"][0]["afferent"], 2);
+        assert_eq!(value["packages"][0]["abstractness"], 0.5);
+    }
+}
@@ -0,0 +1,19 @@
+use clap::ValueEnum;
+
+pub mod graph;
+pub mod json;
+pub mod metrics;
+pub mod sarif;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Text,
+    Json,
+    Sarif,
+}
+
+impl OutputFormat {
+    pub fn is_machine_readable(self) -> bool {
+        !matches!(self, OutputFormat::Text)
+    }
+}
@@ -0,0 +1,357 @@
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::path::{Component, Path};
+
+use crate::analyzer::{Issue, IssueType, Rule, Severity};
+use crate::config::Config;
+use crate::fixes;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+const INFORMATION_URI: &str = "https://github.com/kleeedolinux/dioxide";
+
+#[derive(Serialize)]
+struct Log {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<Run>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Run {
+    tool: Tool,
+    column_kind: &'static str,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct Tool {
+    driver: Driver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Driver {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<ReportingDescriptor>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReportingDescriptor {
+    id: &'static str,
+    name: &'static str,
+    short_description: Message,
+    default_configuration: ReportingConfiguration,
+    properties: RuleProperties,
+}
+
+#[derive(Serialize)]
+struct ReportingConfiguration {
+    level: &'static str,
+}
+
+#[derive(Serialize)]
+struct RuleProperties {
+    category: IssueType,
+}
+
+#[derive(Serialize)]
+struct Message {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: &'static str,
+    rule_index: usize,
+    level: &'static str,
+    message: Message,
+    locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<Fix>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    physical_location: PhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    region: Region,
+}
+
+#[derive(Serialize)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line: usize,
+    start_column: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_column: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    snippet: Option<Message>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Fix {
+    description: Message,
+    artifact_changes: Vec<ArtifactChange>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArtifactChange {
+    artifact_location: ArtifactLocation,
+    replacements: Vec<Replacement>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Replacement {
+    deleted_region: Region,
+    inserted_content: Message,
+}
+
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "note",
+    }
+}
+
+fn artifact_uri(path: &Path) -> String {
+    let mut segments = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::RootDir => segments.push(String::new()),
+            Component::Prefix(prefix) => segments.push(prefix.as_os_str().to_string_lossy().to_string()),
+            Component::ParentDir => segments.push("..".to_string()),
+            Component::Normal(part) => segments.push(percent_encode(&part.to_string_lossy())),
+        }
+    }
+
+    let joined = segments.join("/");
+    if path.is_absolute() {
+        format!("file://{}", joined)
+    } else {
+        joined
+    }
+}
+
+fn percent_encode(segment: &str) -> String {
+    let mut encoded = String::new();
+    for byte in segment.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+fn position(content: &str, byte_offset: usize) -> (usize, usize) {
+    let before = &content[..byte_offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+fn replacement(original: &str, fixed: &str) -> Replacement {
+    let prefix = original
+        .char_indices()
+        .zip(fixed.chars())
+        .find(|((_, a), b)| a != b)
+        .map_or(original.len().min(fixed.len()), |((i, _), _)| i);
+
+    let max_suffix = (original.len() - prefix).min(fixed.len() - prefix);
+    let mut suffix = original
+        .bytes()
+        .rev()
+        .zip(fixed.bytes().rev())
+        .take(max_suffix)
+        .take_while(|(a, b)| a == b)
+        .count();
+    while !original.is_char_boundary(original.len() - suffix) || !fixed.is_char_boundary(fixed.len() - suffix) {
+        suffix -= 1;
+    }
+
+    let (start_line, start_column) = position(original, prefix);
+    let (end_line, end_column) = position(original, original.len() - suffix);
+    Replacement {
+        deleted_region: Region {
+            start_line,
+            start_column,
+            end_line: Some(end_line),
+            end_column: Some(end_column),
+            snippet: None,
+        },
+        inserted_content: Message {
+            text: fixed[prefix..fixed.len() - suffix].to_string(),
+        },
+    }
+}
+
+fn build_fix(issue: &Issue, config: &Config) -> Option<Fix> {
+    let (original, fixed) = fixes::preview_fix(issue, config)?;
+    Some(Fix {
+        description: Message {
+            text: format!("Fix: {}", issue.message.trim()),
+        },
+        artifact_changes: vec![ArtifactChange {
+            artifact_location: ArtifactLocation {
+                uri: artifact_uri(&issue.file_path),
+            },
+            replacements: vec![replacement(&original, &fixed)],
+        }],
+    })
+}
+
+fn build_result(issue: &Issue, config: &Config) -> SarifResult {
+    let snippet = issue.code.trim();
+    SarifResult {
+        rule_id: issue.rule.id(),
+        rule_index: Rule::ALL.iter().position(|rule| *rule == issue.rule).unwrap_or(0),
+        level: level(issue.severity),
+        message: Message {
+            text: issue.message.trim().to_string(),
+        },
+        locations: vec![Location {
+            physical_location: PhysicalLocation {
+                artifact_location: ArtifactLocation {
+                    uri: artifact_uri(&issue.file_path),
+                },
+                region: Region {
+                    start_line: issue.line.max(1),
+                    start_column: issue.column.max(1),
+                    end_line: None,
+                    end_column: None,
+                    snippet: (!snippet.is_empty()).then(|| Message {
+                        text: snippet.to_string(),
+                    }),
+                },
+            },
+        }],
+        fixes: build_fix(issue, config).into_iter().collect(),
+    }
+}
+
+pub fn render(issues: &[Issue], config: &Config) -> Result<String> {
+    let rules = Rule::ALL
+        .iter()
+        .map(|rule| ReportingDescriptor {
+            id: rule.id(),
+            name: rule.name(),
+            short_description: Message {
+                text: rule.description().to_string(),
+            },
+            default_configuration: ReportingConfiguration {
+                level: level(rule.default_severity()),
+            },
+            properties: RuleProperties {
+                category: rule.issue_type(),
+            },
+        })
+        .collect();
+
+    let log = Log {
+        schema: SARIF_SCHEMA,
+        version: SARIF_VERSION,
+        runs: vec![Run {
+            tool: Tool {
+                driver: Driver {
+                    name: env!("CARGO_PKG_NAME"),
+                    version: env!("CARGO_PKG_VERSION"),
+                    information_uri: INFORMATION_URI,
+                    rules,
+                },
+            },
+            column_kind: "unicodeCodePoints",
+            results: issues.iter().map(|issue| build_result(issue, config)).collect(),
+        }],
+    };
+
+    serde_json::to_string_pretty(&log).context("Failed to serialize issues as SARIF")
+}
+
+pub fn print(issues: &[Issue], config: &Config) -> Result<()> {
+    println!("{}", render(issues, config)?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{self, TempDir};
+    use serde_json::Value;
+
+    #[test]
+    fn encodes_artifact_uris() {
+        assert_eq!(artifact_uri(Path::new("./pkg/my file.go")), "pkg/my%20file.go");
+        assert_eq!(artifact_uri(Path::new("../pkg/a+b.go")), "../pkg/a%2Bb.go");
+        assert_eq!(artifact_uri(Path::new("/src/é.go")), "file:///src/%C3%A9.go");
+    }
+
+    #[test]
+    fn replacement_covers_only_the_changed_span() {
+        let change = replacement("package p\n\nfunc f(x int) {}\n", "package p\n\nfunc f(_ int) {}\n");
+        assert_eq!((change.deleted_region.start_line, change.deleted_region.start_column), (3, 8));
+        assert_eq!((change.deleted_region.end_line, change.deleted_region.end_column), (Some(3), Some(9)));
+        assert_eq!(change.inserted_content.text, "_");
+
+        let change = replacement("s := \"é\"\n", "s := \"è\"\n");
+        assert_eq!(change.deleted_region.start_column, 7);
+        assert_eq!(change.deleted_region.end_column, Some(8));
+        assert_eq!(change.inserted_content.text, "è");
+    }
+
+    #[test]
+    fn renders_results_with_rules_and_fixes() {
+        let dir = TempDir::new("sarif");
+        let path = dir.write("p.go", "package p\n\nfunc f() {\n    return\n}\n");
+        let mut issue = test_support::issue(&path, Rule::TabIndentation, 4, 1, "    return");
+        issue.message = "Use tabs for indentation instead of spaces ".to_string();
+        let rendered = render(&[issue], &Config::default()).unwrap();
+
+        let log: Value = serde_json::from_str(&rendered).unwrap();
+        assert_eq!(log["$schema"], SARIF_SCHEMA);
+        assert_eq!(log["version"], "2.1.0");
+        let run = &log["runs"][0];
+        assert_eq!(run["columnKind"], "unicodeCodePoints");
+        assert_eq!(run["tool"]["driver"]["rules"].as_array().unwrap().len(), Rule::ALL.len());
+
+        let result = &run["results"][0];
+        let index = result["ruleIndex"].as_u64().unwrap() as usize;
+        assert_eq!(result["ruleId"], Rule::TabIndentation.id());
+        assert_eq!(run["tool"]["driver"]["rules"][index]["id"], Rule::TabIndentation.id());
+        assert_eq!(result["level"], "note");
+        assert_eq!(result["message"]["text"], "Use tabs for indentation instead of spaces");
+
+        let region = &result["locations"][0]["physicalLocation"]["region"];
+        assert_eq!((region["startLine"].as_u64(), region["startColumn"].as_u64()), (Some(4), Some(1)));
+        assert_eq!(region["snippet"]["text"], "return");
+        let replacement = &result["fixes"][0]["artifactChanges"][0]["replacements"][0];
+        assert_eq!(replacement["deletedRegion"]["endColumn"], 5);
+        assert_eq!(replacement["insertedContent"]["text"], "\t");
+    }
+}
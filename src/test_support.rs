@@ -0,0 +1,59 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::analyzer::{Issue, Rule};
+use crate::parser::{self, GoFile};
+
+pub fn issue(file_path: &Path, rule: Rule, line: usize, column: usize, code: &str) -> Issue {
+    Issue {
+        file_path: file_path.to_path_buf(),
+        line,
+        column,
+        rule,
+        issue_type: rule.issue_type(),
+        severity: rule.default_severity(),
+        message: String::new(),
+        code: code.to_string(),
+        fix_available: true,
+    }
+}
+
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    pub fn new(name: &str) -> TempDir {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        let unique = NEXT.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("dioxide-{}-{}-{}", name, std::process::id(), unique));
+        fs::create_dir_all(&path).unwrap();
+        TempDir { path }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn write(&self, name: &str, content: &str) -> PathBuf {
+        let path = self.path.join(name);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).unwrap();
+        }
+        fs::write(&path, content).unwrap();
+        path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+pub fn parse(source: &str) -> GoFile {
+    let content = source.to_string();
+    let tree = parser::init_parser().unwrap().parse(&content, None).unwrap();
+    GoFile { content, tree }
+}
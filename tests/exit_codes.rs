@@ -0,0 +1,83 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+struct Fixture {
+    dir: PathBuf,
+}
+
+impl Fixture {
+    fn new(name: &str, source: &str) -> Fixture {
+        let dir = std::env::temp_dir().join(format!("dioxide-exit-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("main.go"), source).unwrap();
+        Fixture { dir }
+    }
+
+    fn lint(&self, args: &[&str]) -> Option<i32> {
+        Command::new(env!("CARGO_BIN_EXE_dioxide"))
+            .arg("lint")
+            .arg(&self.dir)
+            .args(args)
+            .output()
+            .unwrap()
+            .status
+            .code()
+    }
+}
+
+impl Drop for Fixture {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+const CLEAN: &str = "package main\n\nfunc main() {\n\tprintln(\"hi\")\n}\n";
+const WARNING: &str = "package main\n\nfunc main() {\n\tprintln(\"hi\")\n}\n\nfunc helper() {\n\tprintln(\"unused\")\n}\n";
+const SYNTAX_ERROR: &str = "package main\n\nfunc main() {\n\tx := := 1\n}\n";
+
+#[test]
+fn clean_code_exits_zero() {
+    let fixture = Fixture::new("clean", CLEAN);
+    assert_eq!(fixture.lint(&[]), Some(0));
+    assert_eq!(fixture.lint(&["--fail-on", "info"]), Some(0));
+}
+
+#[test]
+fn fail_on_sets_the_threshold() {
+    let fixture = Fixture::new("warning", WARNING);
+    assert_eq!(fixture.lint(&[]), Some(0));
+    assert_eq!(fixture.lint(&["--fail-on", "warning"]), Some(1));
+    assert_eq!(fixture.lint(&["--fail-on", "warning", "--format", "json"]), Some(1));
+}
+
+#[test]
+fn fail_on_falls_back_to_config() {
+    let fixture = Fixture::new("config", WARNING);
+    let config = fixture.dir.join("dioxide.toml");
+    Command::new(env!("CARGO_BIN_EXE_dioxide"))
+        .arg("init")
+        .arg(&config)
+        .output()
+        .unwrap();
+    let content = fs::read_to_string(&config).unwrap().replace("fail_on = \"error\"", "fail_on = \"warning\"");
+    fs::write(&config, content).unwrap();
+
+    assert_eq!(fixture.lint(&["--config", config.to_str().unwrap()]), Some(1));
+    assert_eq!(fixture.lint(&["--config", config.to_str().unwrap(), "--fail-on", "error"]), Some(0));
+}
+
+#[test]
+fn errors_exit_one_by_default() {
+    let fixture = Fixture::new("syntax", SYNTAX_ERROR);
+    assert_eq!(fixture.lint(&[]), Some(1));
+}
+
+#[test]
+fn tool_errors_exit_two() {
+    let fixture = Fixture::new("tool", CLEAN);
+    let config = fixture.dir.join("dioxide.toml");
+    fs::write(&config, "[general\n").unwrap();
+    assert_eq!(fixture.lint(&["--config", config.to_str().unwrap()]), Some(2));
+    assert_eq!(fixture.lint(&["--baseline", "/nonexistent/baseline.json"]), Some(2));
+}